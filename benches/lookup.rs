@@ -0,0 +1,96 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// benches/lookup.rs - 标签查找性能基准
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+// 测量 `from_label_id` 与 `from_label_str` 随标签数量（10、100、1000）的变化，
+// 为是否改用 phf / 二分查找等查找策略提供数据；`from_label_str` 同时比较逐个匹配与
+// `optimize = true` 下按长度分派的两种生成方式。
+// fixtures 中的标签文件形如 `"class 0" = 0`，按标签数量命名。
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use shanan_macro::toml_label;
+use std::hint::black_box;
+
+#[toml_label(file = "benches/fixtures/labels_10.toml", trait = "none")]
+enum Labels10 {}
+
+#[toml_label(file = "benches/fixtures/labels_100.toml", trait = "none")]
+enum Labels100 {}
+
+#[toml_label(file = "benches/fixtures/labels_1000.toml", trait = "none")]
+enum Labels1000 {}
+
+#[toml_label(
+  file = "benches/fixtures/labels_10.toml",
+  trait = "none",
+  optimize = true
+)]
+enum Optimized10 {}
+
+#[toml_label(
+  file = "benches/fixtures/labels_100.toml",
+  trait = "none",
+  optimize = true
+)]
+enum Optimized100 {}
+
+#[toml_label(
+  file = "benches/fixtures/labels_1000.toml",
+  trait = "none",
+  optimize = true
+)]
+enum Optimized1000 {}
+
+macro_rules! bench_lookup {
+  ($c:expr, $label:ident, $optimized:ident) => {{
+    let num = $label::LABEL_NUM;
+    let names: Vec<String> = (0..num)
+      .map(|id| $label::from_label_id(id).to_label_str())
+      .collect();
+
+    let mut group = $c.benchmark_group("from_label_id");
+    group.bench_with_input(BenchmarkId::from_parameter(num), &num, |b, &num| {
+      b.iter(|| {
+        for id in 0..num {
+          black_box($label::from_label_id(black_box(id)));
+        }
+      })
+    });
+    group.finish();
+
+    let mut group = $c.benchmark_group("from_label_str");
+    group.bench_with_input(BenchmarkId::from_parameter(num), &names, |b, names| {
+      b.iter(|| {
+        for name in names {
+          black_box($label::from_label_str(black_box(name)));
+        }
+      })
+    });
+    group.finish();
+
+    let mut group = $c.benchmark_group("from_label_str_optimized");
+    group.bench_with_input(BenchmarkId::from_parameter(num), &names, |b, names| {
+      b.iter(|| {
+        for name in names {
+          black_box($optimized::from_label_str(black_box(name)));
+        }
+      })
+    });
+    group.finish();
+  }};
+}
+
+fn lookup(c: &mut Criterion) {
+  bench_lookup!(c, Labels10, Optimized10);
+  bench_lookup!(c, Labels100, Optimized100);
+  bench_lookup!(c, Labels1000, Optimized1000);
+}
+
+criterion_group!(benches, lookup);
+criterion_main!(benches);
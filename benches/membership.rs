@@ -0,0 +1,50 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// benches/membership.rs - 标签名成员判断性能基准
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+// 比较 `name_set = true` 生成的 `is_known_name`（完美哈希）与 `from_label_str(..).is_some()`
+// 判断名称是否已知的开销，输入为 1000 个已知名称与同样数量的未知名称。
+// 以 `cargo bench --bench membership` 运行。
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use shanan_macro::toml_label;
+use std::hint::black_box;
+
+#[toml_label(
+  file = "benches/fixtures/labels_1000.toml",
+  trait = "none",
+  name_set = true
+)]
+enum Labels1000 {}
+
+fn membership(c: &mut Criterion) {
+  let names: Vec<String> = (0..Labels1000::LABEL_NUM)
+    .flat_map(|id| [format!("class {}", id), format!("other {}", id)])
+    .collect();
+
+  let mut group = c.benchmark_group("membership");
+  group.bench_function("phf", |b| {
+    b.iter(|| {
+      for name in &names {
+        black_box(Labels1000::is_known_name(black_box(name)));
+      }
+    })
+  });
+  group.bench_function("from_label_str", |b| {
+    b.iter(|| {
+      for name in &names {
+        black_box(Labels1000::from_label_str(black_box(name)).is_some());
+      }
+    })
+  });
+  group.finish();
+}
+
+criterion_group!(benches, membership);
+criterion_main!(benches);
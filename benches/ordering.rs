@@ -0,0 +1,51 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// benches/ordering.rs - 标签比较性能基准
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+// 比较封闭模式下生成的 `Ord`（转为 id 整数后比较）与按 `to_label_id()` 排序的开销。
+// 待排序的标签由 1000 个标签按固定步长打乱得到，保证每次运行的输入相同。
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use shanan_macro::toml_label;
+use std::hint::black_box;
+
+#[toml_label(
+  file = "benches/fixtures/labels_1000.toml",
+  trait = "none",
+  closed = true
+)]
+enum Labels1000 {}
+
+fn ordering(c: &mut Criterion) {
+  let num = Labels1000::LABEL_NUM;
+  // 7919 与 1000 互素，步进遍历恰好覆盖每个 id 一次
+  let labels: Vec<Labels1000> = (0..num)
+    .map(|i| Labels1000::from_label_id(i * 7919 % num))
+    .collect();
+
+  let mut group = c.benchmark_group("sort");
+  group.bench_function("ord", |b| {
+    b.iter(|| {
+      let mut labels = black_box(labels.clone());
+      labels.sort_unstable();
+      labels
+    })
+  });
+  group.bench_function("by_label_id", |b| {
+    b.iter(|| {
+      let mut labels = black_box(labels.clone());
+      labels.sort_unstable_by_key(Labels1000::to_label_id);
+      labels
+    })
+  });
+  group.finish();
+}
+
+criterion_group!(benches, ordering);
+criterion_main!(benches);
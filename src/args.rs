@@ -0,0 +1,521 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// src/args.rs - 宏参数解析
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{ToTokens, TokenStreamExt};
+use std::collections::HashSet;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Ident, Token};
+
+/// 宏参数，格式为 `key = value, ...`
+pub(crate) struct LabelArgs {
+  /// 主标签来源，`file`、`dir` 或 `out_dir_file` 三者之一
+  pub(crate) source: MainSource,
+  /// 是否派生 `Copy`，默认为 `true`
+  pub(crate) copy: bool,
+  /// 将生成的枚举及其实现包裹在该名称的模块中
+  pub(crate) module: Option<String>,
+  /// 函数式宏 `toml_label_enum!` 生成的枚举名
+  pub(crate) name: Option<Ident>,
+  /// 覆盖生成枚举的可见性
+  pub(crate) vis: Option<syn::Visibility>,
+  /// 是否把 id 重新分配为从 0 开始的连续值
+  pub(crate) densify: bool,
+  /// `from_label_id` 先用该常量表把外部 id 映射为标签 id
+  pub(crate) map_ids_from: Option<syn::Path>,
+  /// `from_label_id` 把不小于该值的 id 都归入 `Unknown(K)`
+  pub(crate) unknown_above: Option<i64>,
+  /// 保留的 id 闭区间，`from_label_id` 把其中的 id 归入 `Reserved(id)`
+  pub(crate) reserved: Vec<(i64, i64)>,
+  /// `from_label_str` 是否先按名称长度分派再精确比较
+  pub(crate) optimize: bool,
+  /// 生成变体名前从标签名开头去掉的前缀
+  pub(crate) strip_prefix: Option<String>,
+  /// 生成变体名前去掉标签名中最后一个该分隔符及其之前的部分
+  pub(crate) strip_delimiter: Option<String>,
+  /// `from_label_str` 是否先去除首尾空白并转为小写再匹配
+  pub(crate) lenient_parse: bool,
+  /// 是否在枚举的文档中列出所有标签
+  pub(crate) doc_table: bool,
+  /// 是否为生成的枚举加上 `#[non_exhaustive]`
+  pub(crate) non_exhaustive: bool,
+  /// `OUTPUT_DIM` 的计算方式
+  pub(crate) output_dim: OutputDim,
+  /// 是否生成比对源文件的测试
+  pub(crate) verify_source: bool,
+  /// 是否实现 `TryFrom<Id>`
+  pub(crate) try_from: bool,
+  /// 是否生成 `from_json`
+  pub(crate) from_json: bool,
+  /// 是否生成 `NAME_SET` 与 `is_known_name`
+  pub(crate) name_set: bool,
+  /// 要实现的标签 trait，为空时生成固有方法
+  pub(crate) label_traits: Vec<syn::Path>,
+  /// 封闭模式：不生成 `Unknown` 变体
+  pub(crate) closed: bool,
+  /// trait 实现（或固有方法）中各项的名称，为 `None` 时不生成该项
+  pub(crate) num_const: Option<Ident>,
+  pub(crate) from_id_method: Option<Ident>,
+  pub(crate) name_method: Option<Ident>,
+  pub(crate) id_method: Option<Ident>,
+  /// 导出 JSON 标签表的路径，相对于 `OUT_DIR`
+  pub(crate) emit_json: Option<String>,
+  /// 需要合并的其他标签文件路径
+  pub(crate) include: Vec<String>,
+  /// 枚举的 `#[repr]` 整数类型，同时也是 id 的类型
+  pub(crate) repr: Option<IdType>,
+  /// 只指定 id 的类型，不加 `#[repr]`
+  pub(crate) id_type: Option<IdType>,
+}
+
+/// 主标签来源
+pub(crate) enum MainSource {
+  /// `file = "labels.toml"`，一个 TOML 标签文件
+  File(String),
+  /// `dir = "labels/"`，每个文件一个标签的目录
+  Dir(String),
+  /// `out_dir_file = "labels.toml"`，构建脚本写入 `OUT_DIR` 的 TOML 标签文件
+  OutDirFile(String),
+}
+
+/// 模型输出维度与标签的对应方式
+pub(crate) enum OutputDim {
+  /// 每个已知标签占一维，维度为标签个数
+  Dense,
+  /// 维度下标即标签 id，维度为最大 id 加一
+  MaxId,
+}
+
+/// 标签 id 可用的整数类型
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IdType {
+  U8,
+  U16,
+  U32,
+  I8,
+  I16,
+  I32,
+  U64,
+  I64,
+}
+
+impl IdType {
+  fn parse(arg: &Arg) -> Result<Self, syn::Error> {
+    match arg.string()?.as_str() {
+      "u8" => Ok(IdType::U8),
+      "u16" => Ok(IdType::U16),
+      "u32" => Ok(IdType::U32),
+      "i8" => Ok(IdType::I8),
+      "i16" => Ok(IdType::I16),
+      "i32" => Ok(IdType::I32),
+      "u64" => Ok(IdType::U64),
+      "i64" => Ok(IdType::I64),
+      _ => Err(arg.error(
+        "expects one of \"u8\", \"u16\", \"u32\", \"u64\", \"i8\", \"i16\", \"i32\", \"i64\"",
+      )),
+    }
+  }
+
+  pub(crate) fn name(self) -> &'static str {
+    match self {
+      IdType::U8 => "u8",
+      IdType::U16 => "u16",
+      IdType::U32 => "u32",
+      IdType::I8 => "i8",
+      IdType::I16 => "i16",
+      IdType::I32 => "i32",
+      IdType::U64 => "u64",
+      IdType::I64 => "i64",
+    }
+  }
+
+  /// 该类型可表示的取值范围；TOML 整数为 `i64`，因此 `u64` 的上限为 `i64::MAX`
+  pub(crate) fn range(self) -> (i64, i64) {
+    match self {
+      IdType::U8 => (0, u8::MAX as i64),
+      IdType::U16 => (0, u16::MAX as i64),
+      IdType::U32 => (0, u32::MAX as i64),
+      IdType::I8 => (i8::MIN as i64, i8::MAX as i64),
+      IdType::I16 => (i16::MIN as i64, i16::MAX as i64),
+      IdType::I32 => (i32::MIN as i64, i32::MAX as i64),
+      IdType::U64 => (0, i64::MAX),
+      IdType::I64 => (i64::MIN, i64::MAX),
+    }
+  }
+
+  pub(crate) fn contains(self, value: i64) -> bool {
+    let (min, max) = self.range();
+    (min..=max).contains(&value)
+  }
+
+  /// 带类型后缀的整数字面量，如 `3u8`
+  pub(crate) fn literal(self, value: i64) -> Literal {
+    format!("{}{}", value, self.name()).parse().unwrap()
+  }
+}
+
+impl ToTokens for IdType {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    tokens.append(Ident::new(self.name(), Span::call_site()));
+  }
+}
+
+impl LabelArgs {
+  /// 生成代码中 id 使用的类型，默认为 `u32`
+  pub(crate) fn id_type(&self) -> IdType {
+    self.repr.or(self.id_type).unwrap_or(IdType::U32)
+  }
+}
+
+impl Parse for LabelArgs {
+  /// 逐个读取 `key = value`，容许结尾多余的逗号
+  fn parse(input: ParseStream) -> syn::Result<Self> {
+    let mut file = None;
+    let mut dir = None;
+    let mut out_dir_file = None;
+    let mut copy = true;
+    let mut module = None;
+    let mut name = None;
+    let mut vis = None;
+    let mut output_dim = OutputDim::Dense;
+    let mut verify_source = false;
+    let mut try_from = false;
+    let mut from_json = false;
+    let mut name_set = false;
+    let mut repr = None;
+    let mut id_type = None;
+    let mut include = Vec::new();
+    let mut emit_json = None;
+    let mut closed = false;
+    let mut non_exhaustive = false;
+    let mut doc_table = false;
+    let mut lenient_parse = false;
+    let mut optimize = false;
+    let mut strip_prefix = None;
+    let mut strip_delimiter = None;
+    let mut unknown_above = None;
+    let mut reserved = Vec::new();
+    let mut map_ids_from = None;
+    let mut densify = false;
+    let mut num_const = Some(Ident::new("LABEL_NUM", Span::call_site()));
+    let mut from_id_method = Some(Ident::new("from_label_id", Span::call_site()));
+    let mut name_method = Some(Ident::new("to_label_str", Span::call_site()));
+    let mut id_method = Some(Ident::new("to_label_id", Span::call_site()));
+    let mut label_traits = vec![syn::parse_quote! { WithLabel }];
+
+    // 重复的参数多半是复制粘贴留下的，不能让后一个悄悄覆盖前一个；只有 `include` 可重复
+    let mut seen = HashSet::new();
+    for arg in Punctuated::<Arg, Token![,]>::parse_terminated(input)? {
+      let key = arg.key.to_string();
+      if key != "include" && !seen.insert(key.clone()) {
+        return Err(syn::Error::new(
+          arg.key.span(),
+          format!("Argument `{}` is given more than once", key),
+        ));
+      }
+      // 三者都决定要实现的 trait，同时给出时后一个会悄悄覆盖前一个
+      const TRAIT_KEYS: [&str; 3] = ["trait", "trait_path", "traits"];
+      if TRAIT_KEYS.contains(&key.as_str())
+        && let Some(other) = TRAIT_KEYS
+          .iter()
+          .find(|other| **other != key && seen.contains(**other))
+      {
+        return Err(syn::Error::new(
+          arg.key.span(),
+          format!(
+            "Only one of `trait`, `trait_path` and `traits` can be used, `{}` is already given",
+            other
+          ),
+        ));
+      }
+      match key.as_str() {
+        "file" => file = Some(arg.string()?),
+        "dir" => dir = Some(arg.string()?),
+        "out_dir_file" => out_dir_file = Some(arg.string()?),
+        "trait" => {
+          label_traits = match arg.string()?.as_str() {
+            "none" => Vec::new(),
+            path => vec![arg.trait_path(path)?],
+          }
+        }
+        "trait_path" => {
+          let path = arg.string()?;
+          label_traits = vec![syn::parse_str(&path).map_err(|_| {
+            arg.error("expects a trait path such as \"::my_core::labels::WithLabel\"")
+          })?]
+        }
+        "traits" => {
+          label_traits = arg
+            .strings()?
+            .iter()
+            .map(|path| arg.trait_path(path))
+            .collect::<syn::Result<_>>()?
+        }
+        "closed" => closed = arg.bool()?,
+        "non_exhaustive" => non_exhaustive = arg.bool()?,
+        "doc_table" => doc_table = arg.bool()?,
+        "lenient_parse" => lenient_parse = arg.bool()?,
+        "optimize" => optimize = arg.bool()?,
+        "strip_prefix" => strip_prefix = Some(arg.string()?),
+        "strip_delimiter" => {
+          let delimiter = arg.string()?;
+          if delimiter.is_empty() {
+            return Err(arg.error("expects a non-empty delimiter"));
+          }
+          strip_delimiter = Some(delimiter);
+        }
+        "unknown_above" => unknown_above = Some(arg.int()?),
+        "reserved" => reserved = arg.ranges()?,
+        "densify" => densify = arg.bool()?,
+        "map_ids_from" => {
+          let path = arg.string()?;
+          map_ids_from =
+            Some(syn::parse_str(&path).map_err(|_| arg.error("expects a path to a constant"))?);
+        }
+        "num_const" => num_const = arg.item_name()?,
+        "from_id_method" => from_id_method = arg.item_name()?,
+        "name_method" => name_method = arg.item_name()?,
+        "id_method" => id_method = arg.item_name()?,
+        "include" => include.push(arg.string()?),
+        "emit_json" => emit_json = Some(arg.string()?),
+        "copy" => copy = arg.bool()?,
+        "module" => {
+          let name = arg.string()?;
+          if syn::parse_str::<Ident>(&name).is_err() {
+            return Err(arg.error("expects a valid module name"));
+          }
+          module = Some(name);
+        }
+        "name" => {
+          let value = arg.string()?;
+          name = Some(
+            syn::parse_str::<Ident>(&value).map_err(|_| arg.error("expects a valid enum name"))?,
+          );
+        }
+        "vis" => {
+          let value = arg.string()?;
+          vis = Some(
+            syn::parse_str::<syn::Visibility>(&value)
+              .map_err(|_| arg.error("expects a visibility such as \"pub(crate)\""))?,
+          );
+        }
+        "repr" => repr = Some(IdType::parse(&arg)?),
+        "id_type" => id_type = Some(IdType::parse(&arg)?),
+        "verify_source" => verify_source = arg.bool()?,
+        "try_from" => try_from = arg.bool()?,
+        "from_json" => from_json = arg.bool()?,
+        "name_set" => name_set = arg.bool()?,
+        "output_dim" => {
+          output_dim = match arg.string()?.as_str() {
+            "dense" => OutputDim::Dense,
+            "max_id" => OutputDim::MaxId,
+            _ => return Err(arg.error("expects \"dense\" or \"max_id\"")),
+          }
+        }
+        other => {
+          return Err(syn::Error::new(
+            arg.key.span(),
+            format!("Unknown argument `{}`", other),
+          ));
+        }
+      }
+    }
+
+    let source = match (file, dir, out_dir_file) {
+      (Some(file), None, None) => MainSource::File(file),
+      (None, Some(dir), None) => MainSource::Dir(dir),
+      (None, None, Some(path)) => MainSource::OutDirFile(path),
+      (None, None, None) => {
+        return Err(syn::Error::new(
+          Span::call_site(),
+          "Missing argument: file = \"path\", dir = \"path\" or out_dir_file = \"path\"",
+        ));
+      }
+      _ => {
+        return Err(syn::Error::new(
+          Span::call_site(),
+          "Only one of `file`, `dir` and `out_dir_file` can be used",
+        ));
+      }
+    };
+
+    if let (Some(repr), Some(id_type)) = (repr, id_type)
+      && repr != id_type
+    {
+      return Err(syn::Error::new(
+        Span::call_site(),
+        format!(
+          "Arguments `repr = \"{}\"` and `id_type = \"{}\"` disagree",
+          repr.name(),
+          id_type.name()
+        ),
+      ));
+    }
+
+    Ok(LabelArgs {
+      source,
+      copy,
+      module,
+      name,
+      vis,
+      output_dim,
+      verify_source,
+      try_from,
+      from_json,
+      name_set,
+      include,
+      emit_json,
+      closed,
+      non_exhaustive,
+      doc_table,
+      lenient_parse,
+      optimize,
+      strip_prefix,
+      strip_delimiter,
+      unknown_above,
+      reserved,
+      map_ids_from,
+      densify,
+      label_traits,
+      num_const,
+      from_id_method,
+      name_method,
+      id_method,
+      repr,
+      id_type,
+    })
+  }
+}
+
+/// 单个 `key = value` 参数
+struct Arg {
+  key: Ident,
+  value: syn::Expr,
+}
+
+impl Parse for Arg {
+  fn parse(input: ParseStream) -> syn::Result<Self> {
+    // 参数名可以是 `trait` 这样的关键字
+    let key = Ident::parse_any(input)?;
+    input.parse::<Token![=]>()?;
+    let value = input.parse()?;
+    Ok(Arg { key, value })
+  }
+}
+
+impl Arg {
+  /// 指向参数值的错误
+  fn error(&self, message: &str) -> syn::Error {
+    syn::Error::new_spanned(&self.value, format!("Argument `{}` {}", self.key, message))
+  }
+
+  fn string(&self) -> syn::Result<String> {
+    match &self.value {
+      syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(lit),
+        ..
+      }) => Ok(lit.value()),
+      _ => Err(self.error("expects a string literal")),
+    }
+  }
+
+  /// 字符串字面量组成的数组，如 `["a", "b"]`
+  fn strings(&self) -> syn::Result<Vec<String>> {
+    let syn::Expr::Array(array) = &self.value else {
+      return Err(self.error("expects an array of string literals"));
+    };
+    array
+      .elems
+      .iter()
+      .map(|elem| match elem {
+        syn::Expr::Lit(syn::ExprLit {
+          lit: syn::Lit::Str(lit),
+          ..
+        }) => Ok(lit.value()),
+        _ => Err(self.error("expects an array of string literals")),
+      })
+      .collect()
+  }
+
+  /// 整数字面量，可带负号
+  fn int(&self) -> syn::Result<i64> {
+    self.int_of(&self.value)
+  }
+
+  /// 参数值中的一个整数字面量，可带负号
+  fn int_of(&self, expr: &syn::Expr) -> syn::Result<i64> {
+    let (negative, expr) = match expr {
+      syn::Expr::Unary(syn::ExprUnary {
+        op: syn::UnOp::Neg(_),
+        expr,
+        ..
+      }) => (true, expr.as_ref()),
+      expr => (false, expr),
+    };
+    match expr {
+      syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(lit),
+        ..
+      }) => lit
+        .base10_parse::<i64>()
+        .map(|value| if negative { -value } else { value })
+        .map_err(|_| self.error("is out of range")),
+      _ => Err(self.error("expects an integer literal")),
+    }
+  }
+
+  /// 闭区间数组，如 `[[100, 199], [300, 300]]`
+  fn ranges(&self) -> syn::Result<Vec<(i64, i64)>> {
+    let error = || self.error("expects an array of `[min, max]` ranges");
+    let syn::Expr::Array(array) = &self.value else {
+      return Err(error());
+    };
+    array
+      .elems
+      .iter()
+      .map(|elem| match elem {
+        syn::Expr::Array(range) if range.elems.len() == 2 => {
+          let (min, max) = (self.int_of(&range.elems[0])?, self.int_of(&range.elems[1])?);
+          if min > max {
+            return Err(self.error(&format!("has an empty range [{}, {}]", min, max)));
+          }
+          Ok((min, max))
+        }
+        _ => Err(error()),
+      })
+      .collect()
+  }
+
+  fn trait_path(&self, path: &str) -> syn::Result<syn::Path> {
+    syn::parse_str(path).map_err(|_| self.error("expects a trait path or \"none\""))
+  }
+
+  fn bool(&self) -> syn::Result<bool> {
+    match &self.value {
+      syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Bool(lit),
+        ..
+      }) => Ok(lit.value),
+      _ => Err(self.error("expects `true` or `false`")),
+    }
+  }
+
+  /// 生成项的名称，`"none"` 表示不生成
+  fn item_name(&self) -> syn::Result<Option<Ident>> {
+    match self.string()?.as_str() {
+      "none" => Ok(None),
+      name => syn::parse_str(name)
+        .map(Some)
+        .map_err(|_| self.error("expects an identifier or \"none\"")),
+    }
+  }
+}
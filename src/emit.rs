@@ -0,0 +1,74 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// src/emit.rs - 将标签表导出给外部工具
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use proc_macro2::Span;
+use std::fmt::Write;
+use std::fs;
+
+use crate::source::{self, Entry};
+
+/// 把合并后的标签表以 JSON 对象 `{"名称": id, ...}`（按 id 升序）写入 `OUT_DIR` 下的 `path`。
+///
+/// 宏展开时写文件并不常见，因此只允许写入构建脚本的 `OUT_DIR`，
+/// 且 `path` 须为不含 `..` 的相对路径。内容未变时不重写文件。
+pub(crate) fn write_json(path: &str, entries: &[Entry]) -> Result<(), syn::Error> {
+  let error = |message: String| syn::Error::new(Span::call_site(), message);
+
+  let target = source::out_dir_path("emit_json", path)?;
+
+  let mut json = String::from("{\n");
+  for (i, entry) in entries.iter().enumerate() {
+    let separator = if i + 1 < entries.len() { "," } else { "" };
+    let _ = writeln!(
+      json,
+      "  {}: {}{}",
+      json_string(&entry.name),
+      entry.id,
+      separator
+    );
+  }
+  json.push_str("}\n");
+
+  if fs::read_to_string(&target).is_ok_and(|existing| existing == json) {
+    return Ok(());
+  }
+  if let Some(parent) = target.parent() {
+    fs::create_dir_all(parent).map_err(|e| {
+      error(format!(
+        "Failed to create directory {}: {}",
+        parent.display(),
+        e
+      ))
+    })?;
+  }
+  fs::write(&target, json)
+    .map_err(|e| error(format!("Failed to write {}: {}", target.display(), e)))
+}
+
+/// 带引号并转义的 JSON 字符串
+fn json_string(s: &str) -> String {
+  let mut out = String::with_capacity(s.len() + 2);
+  out.push('"');
+  for c in s.chars() {
+    match c {
+      '"' => out.push_str("\\\""),
+      '\\' => out.push_str("\\\\"),
+      '\n' => out.push_str("\\n"),
+      '\r' => out.push_str("\\r"),
+      '\t' => out.push_str("\\t"),
+      c if (c as u32) < 0x20 => {
+        let _ = write!(out, "\\u{:04x}", c as u32);
+      }
+      c => out.push(c),
+    }
+  }
+  out.push('"');
+  out
+}
@@ -0,0 +1,1860 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// src/expand.rs - 标签枚举及其实现的代码生成
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::quote;
+use std::path::PathBuf;
+use syn::{DeriveInput, Ident};
+
+use crate::args::{IdType, LabelArgs, MainSource, OutputDim};
+use crate::emit;
+use crate::source::{self, Source};
+
+/// 宏的调用形式
+#[derive(Clone, Copy)]
+pub(crate) enum Form {
+  /// `#[toml_label(...)]`，生成枚举本身及其实现
+  Attribute,
+  /// `#[derive(TomlLabel)]`，枚举由用户写出，只生成实现
+  Derive,
+  /// `toml_label_enum!(name = "...", ...)`，与属性宏相同，但枚举名来自参数
+  Function,
+}
+
+pub(crate) fn expand(
+  args: &LabelArgs,
+  input_ast: &DeriveInput,
+  form: Form,
+) -> Result<TokenStream, syn::Error> {
+  if args.name.is_some() && !matches!(form, Form::Function) {
+    return Err(syn::Error::new(
+      Span::call_site(),
+      "`name` is only accepted by toml_label_enum!",
+    ));
+  }
+
+  // 先读取被包含的文件，再读取主文件（或目录）
+  let mut sources = args
+    .include
+    .iter()
+    .map(|path| Source::read(path))
+    .collect::<Result<Vec<_>, _>>()?;
+  sources.push(match &args.source {
+    MainSource::File(path) => Source::read(path)?,
+    MainSource::Dir(path) => Source::read_dir(path)?,
+    MainSource::OutDirFile(path) => Source::read_out_dir(path)?,
+  });
+
+  let toml_data = source::merge(&sources)?;
+
+  if let Some(path) = &args.emit_json {
+    emit::write_json(path, &toml_data)?;
+  }
+
+  // 模式版本取自主文件
+  let schema_version = sources
+    .last()
+    .and_then(|source| source.version)
+    .map(|version| {
+      quote! {
+        /// 标签文件声明的模式版本
+        pub const SCHEMA_VERSION: u32 = #version;
+      }
+    });
+
+  let enum_name = &input_ast.ident;
+
+  // 检查是否是枚举
+  let syn::Data::Enum(data) = &input_ast.data else {
+    return Err(syn::Error::new(
+      Span::call_site(),
+      "This macro can only be used on enums",
+    ));
+  };
+
+  let id_type = args.id_type();
+
+  if args.closed && toml_data.is_empty() {
+    return Err(syn::Error::new(
+      Span::call_site(),
+      "`closed = true` requires at least one label",
+    ));
+  }
+
+  // 检查 id 是否超出 id 类型的范围，一次列出所有越界的标签，便于修改较大的标签文件
+  let out_of_range: Vec<&source::Entry> = toml_data
+    .iter()
+    .filter(|entry| !id_type.contains(entry.id))
+    .collect();
+  if !out_of_range.is_empty() {
+    let (min, _) = id_type.range();
+    let hint = if min == 0 && out_of_range.iter().any(|entry| entry.id < 0) {
+      "; negative ids require a signed `repr` or `id_type`"
+    } else {
+      ""
+    };
+    let message = match out_of_range.as_slice() {
+      [entry] => format!(
+        "Label `{}` has id {} which does not fit in `{}`{}",
+        entry.name,
+        entry.id,
+        id_type.name(),
+        hint
+      ),
+      _ => format!(
+        "{} label ids do not fit in `{}`: {}{}",
+        out_of_range.len(),
+        id_type.name(),
+        out_of_range
+          .iter()
+          .map(|entry| format!("`{}` has id {}", entry.name, entry.id))
+          .collect::<Vec<_>>()
+          .join(", "),
+        hint
+      ),
+    };
+    return Err(syn::Error::new(Span::call_site(), message));
+  }
+
+  let mut labels: Vec<Label> = Vec::with_capacity(toml_data.len());
+  for source::Entry {
+    name,
+    id,
+    deprecated,
+    display,
+    groups,
+    sort_order,
+    aliases,
+    path,
+  } in toml_data
+  {
+    let ident = variant_ident(strip_namespace(args, &name))?;
+    // 去掉命名空间或清洗后，不同的标签名可能得到同一个变体名
+    let clash = if !args.closed && ident == "Unknown" {
+      Some("the built-in `Unknown` variant".to_string())
+    } else if !args.reserved.is_empty() && ident == "Reserved" {
+      Some("the built-in `Reserved` variant".to_string())
+    } else if ident == "N" {
+      // 变体 `N` 会遮蔽标签个数常量 `N`，`MyLabel::N` 将指向变体
+      Some("the generated label count constant `N`".to_string())
+    } else {
+      labels
+        .iter()
+        .find(|label| label.ident == ident)
+        .map(|label| format!("label `{}`", label.name))
+    };
+    if let Some(clash) = clash {
+      return Err(syn::Error::new(
+        Span::call_site(),
+        format!(
+          "Label `{}` maps to variant `{}`, which clashes with {}",
+          name, ident, clash
+        ),
+      ));
+    }
+    // `densify` 时按 id 升序重新分配连续的 id，原 id 另行保留
+    let (id, original) = if args.densify {
+      (labels.len() as i64, id)
+    } else {
+      (id, id)
+    };
+    labels.push(Label {
+      ident,
+      lit: id_type.literal(id),
+      id,
+      original,
+      name,
+      deprecated,
+      display,
+      groups,
+      sort_order,
+      aliases,
+      path,
+    });
+  }
+
+  if let Form::Derive = form {
+    check_variants(args, data, &labels)?;
+  }
+
+  // 封闭模式下枚举不带字段，判别值即 id
+  let enum_vars = labels.iter().map(
+    |Label {
+       ident,
+       lit,
+       deprecated,
+       ..
+     }| {
+      let deprecated = deprecated
+        .as_ref()
+        .map(|note| quote! { #[deprecated(note = #note)] });
+      if args.closed {
+        quote! {
+          #deprecated
+          #ident = #lit
+        }
+      } else {
+        quote! {
+          #deprecated
+          #ident
+        }
+      }
+    },
+  );
+
+  let vars_id = labels.iter().map(|Label { ident, lit, .. }| {
+    quote! {
+      #lit => #enum_name::#ident
+    }
+  });
+
+  let try_from_id_arm = labels.iter().map(|Label { ident, lit, .. }| {
+    quote! {
+      #lit => Ok(#enum_name::#ident)
+    }
+  });
+
+  let label_name = labels.iter().map(|Label { ident, name, .. }| {
+    quote! {
+      #enum_name::#ident => String::from(#name)
+    }
+  });
+
+  let label_id = labels.iter().map(|Label { ident, lit, .. }| {
+    quote! {
+      #enum_name::#ident => #lit
+    }
+  });
+
+  let canonical_key_arm = labels.iter().map(|Label { ident, name, .. }| {
+    quote! {
+      #enum_name::#ident => #name
+    }
+  });
+
+  let stable_hash_arm = labels.iter().map(|Label { ident, name, .. }| {
+    let hash = fnv1a(name.as_bytes());
+    quote! {
+      #enum_name::#ident => #hash
+    }
+  });
+
+  let original_id = args.densify.then(|| {
+    let arms = labels.iter().map(
+      |Label {
+         ident, original, ..
+       }| {
+        let original = id_type.literal(*original);
+        quote! {
+          #enum_name::#ident => #original
+        }
+      },
+    );
+    let unknown = (!args.closed).then(|| {
+      quote! {
+        #enum_name::Unknown(i) => *i,
+      }
+    });
+    let reserved = (!args.reserved.is_empty()).then(|| {
+      quote! {
+        #enum_name::Reserved(i) => *i,
+      }
+    });
+    quote! {
+      /// 标签文件中的原始 id；`to_label_id` 返回的是重新分配的连续 id，`Unknown` 原样返回
+      pub fn original_id(&self) -> #id_type {
+        match self {
+          #(#arms,)*
+          #unknown
+          #reserved
+        }
+      }
+    }
+  });
+
+  let display_name_arm = labels.iter().map(
+    |Label {
+       ident,
+       name,
+       display,
+       ..
+     }| {
+      let display = display.as_ref().unwrap_or(name);
+      quote! {
+        #enum_name::#ident => #display
+      }
+    },
+  );
+
+  // 宽松模式下标签名与输入按同样的方式规范化后再比较；别名与原始名称共用同一张表，
+  // 因此任何两个名称（原始名称或别名）都不能相同
+  let mut name_keys: Vec<NameKey> = Vec::with_capacity(labels.len());
+  let mut name_sources: Vec<String> = Vec::with_capacity(labels.len());
+  // 别名与原始名称可能来自不同的 `include` 文件，报错时一并给出各自所在的文件
+  for Label {
+    ident,
+    name,
+    aliases,
+    path,
+    ..
+  } in &labels
+  {
+    let candidates = std::iter::once((name, format!("label `{}` ({})", name, path.display())))
+      .chain(aliases.iter().map(|alias| {
+        (
+          alias,
+          format!("alias `{}` of label `{}` ({})", alias, name, path.display()),
+        )
+      }));
+    for (candidate, source) in candidates {
+      let key = if args.lenient_parse {
+        normalize_name(candidate)
+      } else {
+        candidate.clone()
+      };
+      if let Some(position) = name_keys.iter().position(|(other, _, _)| *other == key) {
+        let message = if args.lenient_parse {
+          format!(
+            "Names are indistinguishable with `lenient_parse = true`: {} and {}",
+            name_sources[position], source
+          )
+        } else {
+          format!(
+            "Name `{}` is used by both {} and {}, `from_label_str` would be ambiguous",
+            candidate, name_sources[position], source
+          )
+        };
+        return Err(syn::Error::new(Span::call_site(), message));
+      }
+      name_keys.push((key, ident, candidate));
+      name_sources.push(source);
+    }
+  }
+  // 精确比较标签名的 match
+  let exact_name_match = |keys: &[&NameKey]| {
+    let arms = keys.iter().map(|(key, ident, _)| {
+      quote! {
+        #key => Some(#enum_name::#ident)
+      }
+    });
+    quote! {
+      match name {
+        #(#arms,)*
+        _ => None,
+      }
+    }
+  };
+  // `optimize` 时先按字节长度分派，长度相同的标签名再按最后一个字节分派，
+  // 最后只与落在同一分支中的少数标签名精确比较
+  let from_name_match = if args.optimize {
+    let len_arms = group_by(name_keys.iter(), |(key, _, _)| key.len())
+      .into_iter()
+      .map(|(len, keys)| {
+        let body = if len == 0 || keys.len() == 1 {
+          exact_name_match(&keys)
+        } else {
+          let byte_arms = group_by(keys, |(key, _, _)| key.as_bytes()[len - 1])
+            .into_iter()
+            .map(|(byte, keys)| {
+              let exact = exact_name_match(&keys);
+              quote! {
+                #byte => #exact
+              }
+            });
+          quote! {
+            match name.as_bytes()[#len - 1] {
+              #(#byte_arms,)*
+              _ => None,
+            }
+          }
+        };
+        quote! {
+          #len => #body
+        }
+      });
+    quote! {
+      match name.len() {
+        #(#len_arms,)*
+        _ => None,
+      }
+    }
+  } else {
+    exact_name_match(&name_keys.iter().collect::<Vec<_>>())
+  };
+  let normalize_input = args.lenient_parse.then(|| {
+    quote! {
+      let name = name.trim().to_lowercase();
+      let name = name.as_str();
+    }
+  });
+
+  let name_to_id_arm = labels.iter().map(|Label { lit, name, .. }| {
+    quote! {
+      #name => Some(#lit)
+    }
+  });
+
+  let id_to_name_arm = labels.iter().map(|Label { lit, name, .. }| {
+    quote! {
+      #lit => Some(#name)
+    }
+  });
+
+  let to_index_arm = labels
+    .iter()
+    .enumerate()
+    .map(|(index, Label { ident, .. })| {
+      quote! {
+        #enum_name::#ident => Some(#index)
+      }
+    });
+
+  let from_index_arm = labels
+    .iter()
+    .enumerate()
+    .map(|(index, Label { ident, .. })| {
+      quote! {
+        #index => Some(#enum_name::#ident)
+      }
+    });
+
+  let all_labels = labels.iter().map(|Label { ident, .. }| ident);
+  let dictionary = labels.iter().map(|Label { name, .. }| name);
+
+  // 展示顺序按 `sort_order` 排列，未设置时取 id，相同时再按 id
+  let mut display_order: Vec<usize> = (0..labels.len()).collect();
+  display_order.sort_by_key(|&index| {
+    let label = &labels[index];
+    (label.sort_order.unwrap_or(label.id), label.id)
+  });
+
+  let index_to_id = labels.iter().map(|Label { lit, .. }| lit);
+  let index_len = labels.len();
+
+  let id_to_name = labels.iter().map(|Label { lit, name, .. }| {
+    quote! {
+      (#lit, #name)
+    }
+  });
+
+  let mut by_name: Vec<_> = labels
+    .iter()
+    .map(|Label { lit, name, .. }| (name, lit))
+    .collect();
+  by_name.sort_by_key(|(name, _)| *name);
+  let name_to_id = by_name.iter().map(|(name, lit)| {
+    quote! {
+      (#name, #lit)
+    }
+  });
+
+  // 包裹在模块中时，模块沿用输入的可见性，枚举本身默认为 `pub` 才能从模块外访问
+  let vis = match (&args.vis, &args.module) {
+    (Some(vis), _) => vis.clone(),
+    (None, Some(_)) => syn::parse_quote! { pub },
+    (None, None) => input_ast.vis.clone(),
+  };
+
+  // 分组按首次出现的顺序排列，成员按 id 升序
+  let mut groups: Vec<(&str, Vec<&Ident>)> = Vec::new();
+  for label in &labels {
+    for group in &label.groups {
+      match groups.iter_mut().find(|(name, _)| name == group) {
+        Some((_, members)) => members.push(&label.ident),
+        None => groups.push((group, vec![&label.ident])),
+      }
+    }
+  }
+  let mut group_names: Vec<(String, &str)> = Vec::new();
+  let mut group_items = Vec::with_capacity(groups.len());
+  for (group, members) in &groups {
+    let snake = group_snake_case(group)?;
+    if let Some((_, other)) = group_names.iter().find(|(name, _)| *name == snake) {
+      return Err(syn::Error::new(
+        Span::call_site(),
+        format!(
+          "Groups `{}` and `{}` map to the same name `{}`",
+          other, group, snake
+        ),
+      ));
+    }
+    let group_const = Ident::new(&format!("{}S", snake.to_uppercase()), Span::call_site());
+    let group_method = Ident::new(&format!("is_{}", snake), Span::call_site());
+    let const_doc = format!("分组 `{}` 中的标签，按 id 升序", group);
+    let method_doc = format!("是否属于分组 `{}`", group);
+    group_items.push(quote! {
+      #[doc = #const_doc]
+      pub const #group_const: &'static [Self] = &[#(#enum_name::#members,)*];
+
+      #[doc = #method_doc]
+      pub fn #group_method(&self) -> bool {
+        matches!(self, #(#enum_name::#members)|*)
+      }
+    });
+    group_names.push((snake, group));
+  }
+
+  let label_num = labels.len() as u32;
+
+  // labels 已按 id 升序排列，最后一个即最大 id
+  let max_label_id = labels.last().map(|Label { lit, .. }| {
+    quote! {
+      /// 已知标签中最大的 id
+      pub const MAX_LABEL_ID: #id_type = #lit;
+    }
+  });
+
+  if matches!(args.output_dim, OutputDim::MaxId) && labels.iter().any(|label| label.id < 0) {
+    return Err(syn::Error::new(
+      Span::call_site(),
+      "`output_dim = \"max_id\"` requires all label ids to be non-negative",
+    ));
+  }
+
+  let output_dim = match args.output_dim {
+    OutputDim::Dense => label_num as usize,
+    OutputDim::MaxId => labels.last().map_or(0, |label| label.id as usize + 1),
+  };
+
+  // 第几维对应该标签，与 `OUTPUT_DIM` 的计算方式一致
+  let output_position_arm = labels
+    .iter()
+    .enumerate()
+    .map(|(index, Label { ident, id, .. })| {
+      let position = match args.output_dim {
+        OutputDim::Dense => index,
+        OutputDim::MaxId => *id as usize,
+      };
+      quote! {
+        #enum_name::#ident => Some(#position)
+      }
+    });
+
+  // 第几维对应的标签，`output_position_arm` 的逆映射
+  let from_position = match args.output_dim {
+    OutputDim::Dense => quote! { #enum_name::from_index(position) },
+    OutputDim::MaxId => {
+      let arms = labels.iter().map(|Label { ident, id, .. }| {
+        let position = *id as usize;
+        quote! {
+          #position => Some(#enum_name::#ident)
+        }
+      });
+      quote! {
+        match position {
+          #(#arms,)*
+          _ => None,
+        }
+      }
+    }
+  };
+
+  let copy_derive = if args.copy {
+    quote! { Copy, }
+  } else {
+    quote! {}
+  };
+
+  // 不实现 trait 时，这些方法作为固有方法生成，需要 `pub`
+  let item_vis = if !args.label_traits.is_empty() {
+    quote! {}
+  } else {
+    quote! { pub }
+  };
+
+  // 不小于阈值的 id 都视为"其他"，统一归入 `Unknown(K)`
+  let collapse_unknown = match args.unknown_above {
+    Some(_) if args.closed => {
+      return Err(syn::Error::new(
+        Span::call_site(),
+        "`unknown_above` requires an `Unknown` variant and cannot be used with `closed = true`",
+      ));
+    }
+    Some(threshold) => {
+      if !id_type.contains(threshold) {
+        return Err(syn::Error::new(
+          Span::call_site(),
+          format!(
+            "`unknown_above = {}` does not fit in `{}`",
+            threshold,
+            id_type.name()
+          ),
+        ));
+      }
+      if let Some(label) = labels.iter().find(|label| label.id >= threshold) {
+        return Err(syn::Error::new(
+          Span::call_site(),
+          format!(
+            "Label `{}` has id {}, which would be collapsed by `unknown_above = {}`",
+            label.name, label.id, threshold
+          ),
+        ));
+      }
+      let threshold = id_type.literal(threshold);
+      Some(quote! {
+        i if i >= #threshold => #enum_name::Unknown(#threshold),
+      })
+    }
+    None => None,
+  };
+
+  // 保留区间内的 id 归入 `Reserved(id)`，与真正未知的 id 区分开
+  if !args.reserved.is_empty() && args.closed {
+    return Err(syn::Error::new(
+      Span::call_site(),
+      "`reserved` requires a `Reserved` variant and cannot be used with `closed = true`",
+    ));
+  }
+  for &(min, max) in &args.reserved {
+    if !id_type.contains(min) || !id_type.contains(max) {
+      return Err(syn::Error::new(
+        Span::call_site(),
+        format!(
+          "Reserved range [{}, {}] does not fit in `{}`",
+          min,
+          max,
+          id_type.name()
+        ),
+      ));
+    }
+    if let Some(label) = labels.iter().find(|label| (min..=max).contains(&label.id)) {
+      return Err(syn::Error::new(
+        Span::call_site(),
+        format!(
+          "Label `{}` has id {}, which lies in the reserved range [{}, {}]",
+          label.name, label.id, min, max
+        ),
+      ));
+    }
+  }
+  let has_reserved = !args.reserved.is_empty();
+  let reserved_variant = has_reserved.then(|| quote! { Reserved(#id_type), });
+  let reserved_bounds = args.reserved.iter().map(|&(min, max)| {
+    let (min, max) = (id_type.literal(min), id_type.literal(max));
+    quote! { (#min, #max) }
+  });
+  let reserved_ids = has_reserved.then(|| {
+    quote! {
+      /// `reserved` 声明的保留 id 闭区间 `(最小值, 最大值)`，其中的 id 构造为 `Reserved`
+      pub const RESERVED_IDS: &'static [(#id_type, #id_type)] = &[#(#reserved_bounds,)*];
+    }
+  });
+  let from_reserved_id = args.reserved.iter().map(|&(min, max)| {
+    let (min, max) = (id_type.literal(min), id_type.literal(max));
+    quote! {
+      i @ #min..=#max => #enum_name::Reserved(i),
+    }
+  });
+  let from_reserved_id = quote! { #(#from_reserved_id)* };
+  let reserved_label_str = has_reserved.then(|| {
+    quote! {
+      #enum_name::Reserved(i) => format!("reserved{}", i),
+    }
+  });
+  let reserved_label_id = has_reserved.then(|| {
+    quote! {
+      #enum_name::Reserved(i) => *i,
+    }
+  });
+  let reserved_key = has_reserved.then(|| {
+    quote! {
+      #enum_name::Reserved(_) => "reserved",
+    }
+  });
+  let reserved_hash = has_reserved.then(|| {
+    quote! {
+      #enum_name::Reserved(i) => {
+        let mut hash: u64 = #FNV_OFFSET_BASIS;
+        for byte in format!("reserved{}", i).bytes() {
+          hash ^= byte as u64;
+          hash = hash.wrapping_mul(#FNV_PRIME);
+        }
+        hash
+      }
+    }
+  });
+  let reserved_index = has_reserved.then(|| {
+    quote! {
+      #enum_name::Reserved(_) => None,
+    }
+  });
+
+  // 封闭模式下没有 `Unknown` 变体，未知 id 无处安放，只能 panic
+  let unknown_variant = (!args.closed).then(|| quote! { Unknown(#id_type), });
+  let from_unknown_id = if args.closed {
+    quote! {
+      i => panic!("Unknown label id {} for {}", i, stringify!(#enum_name))
+    }
+  } else {
+    quote! {
+      i => #enum_name::Unknown(i)
+    }
+  };
+  let unknown_label_str = (!args.closed).then(|| {
+    quote! {
+      #enum_name::Unknown(i) => format!("unknown{}", i),
+      #reserved_label_str
+    }
+  });
+  let unknown_label_id = (!args.closed).then(|| {
+    quote! {
+      #enum_name::Unknown(i) => *i,
+      #reserved_label_id
+    }
+  });
+  let unknown_key = (!args.closed).then(|| {
+    quote! {
+      #enum_name::Unknown(_) => "unknown",
+      #reserved_key
+    }
+  });
+  let unknown_hash = (!args.closed).then(|| {
+    quote! {
+      #enum_name::Unknown(i) => {
+        let mut hash: u64 = #FNV_OFFSET_BASIS;
+        for byte in format!("unknown{}", i).bytes() {
+          hash ^= byte as u64;
+          hash = hash.wrapping_mul(#FNV_PRIME);
+        }
+        hash
+      }
+      #reserved_hash
+    }
+  });
+  let unknown_index = (!args.closed).then(|| {
+    quote! {
+      #enum_name::Unknown(_) => None,
+      #reserved_index
+    }
+  });
+
+  // 各项可按参数改名，或以 "none" 省略
+  let num_const = args.num_const.as_ref().map(|name| {
+    quote! {
+      #item_vis const #name: u32 = #label_num;
+    }
+  });
+  let id_match = quote! {
+    match label_id {
+      #(#vars_id,)*
+      #from_reserved_id
+      #collapse_unknown
+      #from_unknown_id,
+    }
+  };
+  // 外部 id 先经用户的常量表映射为标签 id，表外的 id 按未知 id 处理
+  let from_id_body = match &args.map_ids_from {
+    Some(table) => {
+      let unmapped = if args.closed {
+        quote! {
+          panic!("Label id {} is not covered by {}", label_id, stringify!(#table))
+        }
+      } else {
+        quote! { #enum_name::Unknown(label_id) }
+      };
+      quote! {
+        match usize::try_from(label_id).ok().and_then(|index| #table.get(index)) {
+          Some(&label_id) => #id_match,
+          None => #unmapped,
+        }
+      }
+    }
+    None => id_match,
+  };
+  let from_id_method = args.from_id_method.as_ref().map(|name| {
+    quote! {
+      #item_vis fn #name(label_id: #id_type) -> Self {
+        #from_id_body
+      }
+    }
+  });
+
+  // 封闭模式下没有 `Unknown` 变体可返回，不生成 `from_c_int`。
+  // 超出 id 类型范围的值不能饱和到类型的最小值或最大值，那可能正是某个标签的 id，
+  // 因此在展开时选出不属于任何标签、也不在保留区间内的 id 作为 `Unknown` 中的哨兵值；
+  // 设置了 `unknown_above` 时上溢的值同样归入 `Unknown(K)`
+  let from_c_int = if args.closed {
+    None
+  } else {
+    let (min, max) = id_type.range();
+    let free = |id: i64| {
+      !labels.iter().any(|label| label.id == id)
+        && !args
+          .reserved
+          .iter()
+          .any(|&(low, high)| (low..=high).contains(&id))
+    };
+    // 比 `i32` 宽的无符号类型（`u32`、`u64`）中，`i32` 的非负值只落在 `0..=i32::MAX`，
+    // 从类型的最大值往下选哨兵，负数得到的 `Unknown` 就不会与某个范围内的输入相同；
+    // TOML 整数不超过 `i64::MAX`，因此 `u64::MAX` 总是空闲的
+    let literal = |id: i64| {
+      let id = id_type.literal(id);
+      quote! { #id }
+    };
+    let below = (min > i64::from(i32::MIN)).then(|| match id_type {
+      IdType::U64 => Some(quote! { #id_type::MAX }),
+      IdType::U32 => unused_id(&labels, &args.reserved, max, i64::from(i32::MAX) + 1, -1)
+        .or_else(|| unused_id(&labels, &args.reserved, min, max, 1))
+        .map(literal),
+      _ => unused_id(&labels, &args.reserved, min, max, 1).map(literal),
+    });
+    let above = (max < i64::from(i32::MAX)).then(|| {
+      args
+        .unknown_above
+        .filter(|&threshold| free(threshold))
+        .or_else(|| unused_id(&labels, &args.reserved, max, min, -1))
+    });
+    let doc = quote! {
+      /// 由 FFI 传入的 C `int` 构造标签：在 id 类型范围内（含负数，若 id 类型有符号）时同
+      /// `from_label_id`，超出范围时返回 `Unknown`，其中的 id 是展开时选出的、不属于任何
+      /// 标签的值，不会被误当作真实标签
+    };
+    let body = match (below, above) {
+      // id 类型容纳所有 `i32`
+      (None, None) => Some(quote! {
+        let label_id = #id_type::from(value);
+        #from_id_body
+      }),
+      (Some(Some(below)), None) => Some(quote! {
+        match #id_type::try_from(value) {
+          Ok(label_id) => #from_id_body,
+          Err(_) => #enum_name::Unknown(#below),
+        }
+      }),
+      (Some(Some(below)), Some(Some(above))) => {
+        let above = id_type.literal(above);
+        Some(quote! {
+          match #id_type::try_from(value) {
+            Ok(label_id) => #from_id_body,
+            Err(_) if value < 0 => #enum_name::Unknown(#below),
+            Err(_) => #enum_name::Unknown(#above),
+          }
+        })
+      }
+      // 标签与保留区间占满了 id 类型，没有可用的哨兵值
+      _ => None,
+    };
+    body.map(|body| {
+      quote! {
+        #doc
+        pub fn from_c_int(value: i32) -> Self {
+          #body
+        }
+      }
+    })
+  };
+
+  // 由 `label_id` 得到 `Option<Self>`，封闭模式下未知 id 不能 panic，改为返回 `None`；
+  // `try_from_label_id` 不经过 `map_ids_from`，因此先查表，表外的 id 同样返回 `None`
+  let from_id = match (&args.map_ids_from, args.closed) {
+    (Some(table), true) => quote! {
+      usize::try_from(label_id)
+        .ok()
+        .and_then(|index| #table.get(index))
+        .and_then(|&label_id| #enum_name::try_from_label_id(label_id).ok())
+    },
+    (None, true) => quote! { #enum_name::try_from_label_id(label_id).ok() },
+    (_, false) => quote! { Some(#from_id_body) },
+  };
+
+  // 由 `name_set = true` 开启，使用方须自行依赖带 `macros` 特性的 `phf`
+  // 集合中存放与 `from_label_str` 相同的比较键，宽松模式下即规范化后的名称
+  let name_set = args.name_set.then(|| {
+    let keys = name_keys.iter().map(|(key, _, _)| key);
+    quote! {
+      /// 所有标签原始名称及别名组成的完美哈希集合，`lenient_parse` 时为规范化后的名称
+      pub const NAME_SET: ::phf::Set<&'static str> = ::phf::phf_set! { #(#keys),* };
+
+      /// 是否为已知的标签名，通过 `NAME_SET` 以 O(1) 判断，无需构造枚举；
+      /// 与 `from_label_str` 一致，`lenient_parse` 时忽略首尾空白与大小写
+      pub fn is_known_name(name: &str) -> bool {
+        #normalize_input
+        #enum_name::NAME_SET.contains(name)
+      }
+    }
+  });
+
+  // 由 `from_json = true` 开启，使用方须自行依赖 `serde_json`
+  let from_json = args.from_json.then(|| {
+    quote! {
+      /// 由 JSON 值构造标签：整数按 id 解析（同 `from_label_id`），字符串按标签名解析
+      /// （同 `from_label_str`），其他值或超出 id 类型范围的整数返回 `None`
+      pub fn from_json(value: &::serde_json::Value) -> Option<Self> {
+        match value {
+          ::serde_json::Value::Number(number) => {
+            let label_id = number
+              .as_i64()
+              .and_then(|id| #id_type::try_from(id).ok())
+              .or_else(|| number.as_u64().and_then(|id| #id_type::try_from(id).ok()))?;
+            #from_id
+          }
+          ::serde_json::Value::String(name) => #enum_name::from_label_str(name),
+          _ => None,
+        }
+      }
+    }
+  });
+  let name_method = args.name_method.as_ref().map(|name| {
+    quote! {
+      #item_vis fn #name(&self) -> String {
+        match self {
+          #(#label_name,)*
+          #unknown_label_str
+        }
+      }
+    }
+  });
+  let id_method = args.id_method.as_ref().map(|name| {
+    quote! {
+      #item_vis fn #name(&self) -> #id_type {
+        match self {
+          #(#label_id, )*
+          #unknown_label_id
+        }
+      }
+    }
+  });
+
+  let label_items = quote! {
+    #num_const
+    #from_id_method
+    #name_method
+    #id_method
+  };
+
+  // 每个 trait 各生成一份相同的实现
+  let label_impl = if args.label_traits.is_empty() {
+    quote! {
+      #[allow(deprecated)]
+      impl #enum_name {
+        #label_items
+      }
+    }
+  } else {
+    let label_traits = &args.label_traits;
+    quote! {
+      #(
+        #[allow(deprecated)]
+        impl #label_traits for #enum_name {
+          #label_items
+        }
+      )*
+    }
+  };
+
+  // 封闭模式总是带上 `#[repr]`，保证 `as` 转换得到的就是 id
+  let repr = if args.closed || args.repr.is_some() {
+    Some(quote! { #[repr(#id_type)] })
+  } else {
+    None
+  };
+
+  // 每次调用生成一个错误类型，以枚举名区分，避免同一作用域中的多个枚举冲突
+  let invalid_name = Ident::new(&format!("Invalid{}", enum_name), Span::call_site());
+  let invalid_doc = format!(
+    "`{}::try_from_label_id` 遇到未知 id 时返回的错误",
+    enum_name
+  );
+  let invalid_def = quote! {
+    #[doc = #invalid_doc]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #vis struct #invalid_name(pub #id_type);
+
+    impl ::std::fmt::Display for #invalid_name {
+      fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "invalid label id {} for {}", self.0, stringify!(#enum_name))
+      }
+    }
+
+    impl ::std::error::Error for #invalid_name {}
+  };
+
+  // `TryFrom<Id>` 与使用方自己的 `impl From<Id>` 冲突（E0119），因此须显式开启
+  let try_from_impl = args.try_from.then(|| {
+    quote! {
+      impl ::std::convert::TryFrom<#id_type> for #enum_name {
+        type Error = #invalid_name;
+
+        /// 同 `try_from_label_id`
+        fn try_from(id: #id_type) -> Result<Self, Self::Error> {
+          Self::try_from_label_id(id)
+        }
+      }
+    }
+  });
+
+  // `from_logits` 的错误类型，与 `Invalid{Enum}` 一样以枚举名区分
+  let logits_error_name = Ident::new(&format!("{}LogitsError", enum_name), Span::call_site());
+  let logits_error_doc = format!("`{}::from_logits` 返回的错误", enum_name);
+  let logits_error_def = quote! {
+    #[doc = #logits_error_doc]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #vis enum #logits_error_name {
+      /// 输出长度与 `OUTPUT_DIM` 不符
+      Length {
+        /// 期望的长度，即 `OUTPUT_DIM`
+        expected: usize,
+        /// 实际的长度
+        found: usize,
+      },
+      /// 该维度的值为 NaN
+      NotANumber(usize),
+      /// 最大值所在的维度没有对应的标签（`max_id` 模式下的 id 空缺，或输出为空）
+      NoLabel(usize),
+    }
+
+    impl ::std::fmt::Display for #logits_error_name {
+      fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+          #logits_error_name::Length { expected, found } => write!(
+            f,
+            "expected {} logits for {} but found {}",
+            expected,
+            stringify!(#enum_name),
+            found
+          ),
+          #logits_error_name::NotANumber(position) => {
+            write!(f, "logit {} is NaN", position)
+          }
+          #logits_error_name::NoLabel(position) => write!(
+            f,
+            "output position {} has no label in {}",
+            position,
+            stringify!(#enum_name)
+          ),
+        }
+      }
+    }
+
+    impl ::std::error::Error for #logits_error_name {}
+  };
+
+  // 按稠密下标存放的每标签数据，可直接以标签作下标
+  let indexed_name = Ident::new(&format!("{}Indexed", enum_name), Span::call_site());
+  let indexed_doc = format!(
+    "按 `{}` 的稠密下标存放的每标签数据，可写作 `stats[{}::...]`",
+    enum_name, enum_name
+  );
+  // 类型参数不用 `T`，以免与名为 `T` 的枚举冲突
+  let indexed_def = quote! {
+    #[doc = #indexed_doc]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #vis struct #indexed_name<__T>(pub [__T; #index_len]);
+
+    impl<__T> #indexed_name<__T> {
+      /// 标签对应的数据，`Unknown` 返回 `None`
+      pub fn get(&self, label: &#enum_name) -> Option<&__T> {
+        label.index().map(|index| &self.0[index])
+      }
+
+      /// 标签对应数据的可变引用，`Unknown` 返回 `None`
+      pub fn get_mut(&mut self, label: &#enum_name) -> Option<&mut __T> {
+        label.index().map(|index| &mut self.0[index])
+      }
+    }
+
+    impl<__T> ::std::ops::Index<#enum_name> for #indexed_name<__T> {
+      type Output = __T;
+
+      /// `Unknown` 没有稠密下标，以其作下标时 panic
+      fn index(&self, label: #enum_name) -> &__T {
+        self.get(&label).unwrap_or_else(|| {
+          panic!("Label without a dense index used to index {}", stringify!(#indexed_name))
+        })
+      }
+    }
+
+    impl<__T> ::std::ops::IndexMut<#enum_name> for #indexed_name<__T> {
+      fn index_mut(&mut self, label: #enum_name) -> &mut __T {
+        let index = label.index().unwrap_or_else(|| {
+          panic!("Label without a dense index used to index {}", stringify!(#indexed_name))
+        });
+        &mut self.0[index]
+      }
+    }
+  };
+
+  let non_exhaustive = args.non_exhaustive.then(|| quote! { #[non_exhaustive] });
+
+  // 标签较多时表格会很长，因此需显式开启
+  let doc_table = args.doc_table.then(|| {
+    let rows = labels.iter().map(|label| {
+      let name = label.name.replace('|', "\\|");
+      format!(" | `{}` | `{}` | {} |", name, label.ident, label.id)
+    });
+    quote! {
+      #[doc = " | 标签 | 变体 | id |"]
+      #[doc = " | --- | --- | --- |"]
+      #(#[doc = #rows])*
+    }
+  });
+
+  // 封闭模式下判别值即 id，比较时直接转为整数，比逐个 match 更快；
+  // `as` 转换需要按值取出，因此要求派生 `Copy`
+  let ord_impl = (args.closed && args.copy && !matches!(form, Form::Derive)).then(|| {
+    quote! {
+      impl ::std::cmp::PartialOrd for #enum_name {
+        fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+          Some(self.cmp(other))
+        }
+      }
+
+      impl ::std::cmp::Ord for #enum_name {
+        /// 按 id 比较
+        fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+          (*self as #id_type).cmp(&(*other as #id_type))
+        }
+      }
+    }
+  });
+
+  // 派生宏不能改写原枚举，只生成实现
+  let enum_def = match form {
+    Form::Attribute | Form::Function => Some(quote! {
+      #doc_table
+      #[derive(Debug, Clone, #copy_derive PartialEq, Eq, Hash)]
+      #[allow(non_camel_case_types)]
+      #non_exhaustive
+      #repr
+      #vis enum #enum_name {
+          #(#enum_vars,)*
+          #unknown_variant
+          #reserved_variant
+      }
+    }),
+    Form::Derive => None,
+  };
+
+  let expanded = quote! {
+    #enum_def
+
+    #invalid_def
+    #try_from_impl
+
+    #ord_impl
+
+    #indexed_def
+
+    #logits_error_def
+
+    #label_impl
+
+    #[allow(deprecated)]
+    impl #enum_name {
+      /// 按 id 升序排列的 `(id, 名称)` 表
+      pub const ID_TO_NAME: &'static [(#id_type, &'static str)] = &[#(#id_to_name,)*];
+      /// 按名称升序排列的 `(名称, id)` 表
+      pub const NAME_TO_ID: &'static [(&'static str, #id_type)] = &[#(#name_to_id,)*];
+
+      #max_label_id
+
+      #reserved_ids
+
+      #schema_version
+
+      /// 模型输出维度，用于确定 softmax 等输出缓冲区的大小。
+      ///
+      /// 不变式：`dense` 模式下等于 `LABEL_NUM`，第 `i` 维对应按 id 升序的第 `i` 个标签；
+      /// `max_id` 模式下等于 `MAX_LABEL_ID + 1`，第 `i` 维对应 id 为 `i` 的标签。
+      pub const OUTPUT_DIM: usize = #output_dim;
+
+      /// id 类型（即 `repr` 给出的整数类型）的字节数，可用于计算存放 id 的缓冲区大小
+      pub const REPR_BYTES: usize = ::core::mem::size_of::<#id_type>();
+
+      /// 标签个数，与 `LABEL_NUM` 相同但类型为 `usize`，可直接用作常量泛型参数，
+      /// 如 `Model<{ Self::N }>`
+      pub const N: usize = #index_len;
+
+      /// 所有已知标签，按 id 升序，不含 `Unknown`
+      pub const ALL: &'static [Self] = &[#(#enum_name::#all_labels,)*];
+
+      /// 按 id 升序遍历所有已知标签
+      pub fn iter() -> impl Iterator<Item = Self> {
+        (0..#index_len).filter_map(#enum_name::from_index)
+      }
+
+      /// 按 id 升序遍历满足 `pred` 的已知标签
+      pub fn iter_where(pred: fn(&Self) -> bool) -> impl Iterator<Item = Self> {
+        #enum_name::iter().filter(move |label| pred(label))
+      }
+
+      /// 按 `sort_order` 遍历所有已知标签，未设置 `sort_order` 的标签以 id 参与排序，
+      /// 供界面展示使用，不影响 id 与 `to_label_id`
+      pub fn iter_display_order() -> impl Iterator<Item = Self> {
+        const ORDER: [usize; #index_len] = [#(#display_order,)*];
+        ORDER.into_iter().filter_map(#enum_name::from_index)
+      }
+
+      /// 按稠密下标排列的标签名
+      pub const DICTIONARY: &'static [&'static str] = &[#(#dictionary,)*];
+
+      /// 按 id 升序排列的所有原始键，即 `DICTIONARY`，可直接用作下拉框或命令行参数的候选值
+      pub fn all_names() -> &'static [&'static str] {
+        #enum_name::DICTIONARY
+      }
+
+      /// 稠密下标到 id 的查找表，`INDEX_TO_ID[i]` 即 `from_index(i)` 的 id
+      pub const INDEX_TO_ID: [#id_type; #index_len] = [#(#index_to_id,)*];
+
+      /// 稠密下标，即按 id 升序排列时的位置，`Unknown` 返回 `None`
+      pub fn index(&self) -> Option<usize> {
+        match self {
+          #(#to_index_arm,)*
+          #unknown_index
+        }
+      }
+
+      /// 独热向量，长度为 `OUTPUT_DIM`，标签对应的维度为 `1.0`，`Unknown` 为全零
+      pub fn to_onehot(&self) -> [f32; #output_dim] {
+        let mut onehot = [0.0; #output_dim];
+        let position: Option<usize> = match self {
+          #(#output_position_arm,)*
+          #unknown_index
+        };
+        if let Some(position) = position {
+          onehot[position] = 1.0;
+        }
+        onehot
+      }
+
+      /// 取模型输出中最大值所在的维度作为标签，维度的含义与 `to_onehot` 一致。
+      ///
+      /// 长度须等于 `OUTPUT_DIM`；含 NaN 时返回错误；最大值不唯一时取第一个。
+      pub fn from_logits(logits: &[f32]) -> Result<Self, #logits_error_name> {
+        if logits.len() != #output_dim {
+          return Err(#logits_error_name::Length {
+            expected: #output_dim,
+            found: logits.len(),
+          });
+        }
+        let mut position = 0;
+        for (i, &logit) in logits.iter().enumerate() {
+          if logit.is_nan() {
+            return Err(#logits_error_name::NotANumber(i));
+          }
+          if logit > logits[position] {
+            position = i;
+          }
+        }
+        let label: Option<Self> = #from_position;
+        label.ok_or(#logits_error_name::NoLabel(position))
+      }
+
+      /// 由稠密下标构造标签，越界时返回 `None`
+      pub fn from_index(index: usize) -> Option<Self> {
+        match index {
+          #(#from_index_arm,)*
+          _ => None,
+        }
+      }
+
+      /// 按 id 顺序的下一个标签，已是最后一个或为 `Unknown` 时返回 `None`
+      #[allow(clippy::should_implement_trait)]
+      pub fn next(&self) -> Option<Self> {
+        self.index().and_then(|index| #enum_name::from_index(index + 1))
+      }
+
+      /// 按 id 顺序的上一个标签，已是第一个或为 `Unknown` 时返回 `None`
+      pub fn prev(&self) -> Option<Self> {
+        self
+          .index()
+          .and_then(|index| index.checked_sub(1))
+          .and_then(#enum_name::from_index)
+      }
+
+      /// 标签文件中的原始键，不受展示名等设置影响，`Unknown` 返回 `"unknown"`
+      pub fn canonical_key(&self) -> &'static str {
+        match self {
+          #(#canonical_key_arm,)*
+          #unknown_key
+        }
+      }
+
+      /// 原始键的 FNV-1a 64 位哈希，编译期算出，与本地 id 分配无关，可用于跨进程标识标签；
+      /// `Unknown` 为 `to_label_str()` 的哈希
+      pub fn stable_hash(&self) -> u64 {
+        match self {
+          #(#stable_hash_arm,)*
+          #unknown_hash
+        }
+      }
+
+      /// 展示名，未设置 `display` 时即原始键，`Unknown` 返回 `"unknown"`
+      pub fn display_name(&self) -> &'static str {
+        match self {
+          #(#display_name_arm,)*
+          #unknown_key
+        }
+      }
+
+      #from_json
+
+      #name_set
+
+      #from_c_int
+
+      #original_id
+
+      /// 先按标签名解析，失败时再按整数 id 解析（同 `from_label_id`），
+      /// 两者都失败（或封闭模式下 id 未知）时返回 `None`，适用于命令行与配置文件
+      pub fn parse(s: &str) -> Option<Self> {
+        #enum_name::from_label_str(s).or_else(|| {
+          let label_id = s.parse::<#id_type>().ok()?;
+          #from_id
+        })
+      }
+
+      /// 由标签名构造标签，未知名称返回 `None`；`lenient_parse` 时忽略首尾空白与大小写
+      pub fn from_label_str(name: &str) -> Option<Self> {
+        #normalize_input
+        #from_name_match
+      }
+
+      /// 由标签名查 id，未知名称返回 `None`
+      pub fn label_id_of(name: &str) -> Option<#id_type> {
+        match name {
+          #(#name_to_id_arm,)*
+          _ => None,
+        }
+      }
+
+      /// `label_id_of` 的 `const` 版本，可用于 `const` 项中在编译期校验标签名。
+      ///
+      /// `const fn` 中无法直接匹配字符串，因此在 `NAME_TO_ID` 上按字节比较做二分查找。
+      pub const fn id_of(name: &str) -> Option<#id_type> {
+        const fn compare(a: &[u8], b: &[u8]) -> core::cmp::Ordering {
+          let mut i = 0;
+          while i < a.len() && i < b.len() {
+            if a[i] != b[i] {
+              return if a[i] < b[i] {
+                core::cmp::Ordering::Less
+              } else {
+                core::cmp::Ordering::Greater
+              };
+            }
+            i += 1;
+          }
+          if a.len() < b.len() {
+            core::cmp::Ordering::Less
+          } else if a.len() > b.len() {
+            core::cmp::Ordering::Greater
+          } else {
+            core::cmp::Ordering::Equal
+          }
+        }
+
+        let name = name.as_bytes();
+        let table = #enum_name::NAME_TO_ID;
+        let (mut low, mut high) = (0, table.len());
+        while low < high {
+          let mid = low + (high - low) / 2;
+          match compare(table[mid].0.as_bytes(), name) {
+            core::cmp::Ordering::Less => low = mid + 1,
+            core::cmp::Ordering::Greater => high = mid,
+            core::cmp::Ordering::Equal => return Some(table[mid].1),
+          }
+        }
+        None
+      }
+
+      /// 由 id 构造标签，未知 id 返回错误而不是 `Unknown`，适用于不可信的输入；
+      /// 参数是标签 id，不经过 `map_ids_from` 的映射
+      pub fn try_from_label_id(id: #id_type) -> Result<Self, #invalid_name> {
+        match id {
+          #(#try_from_id_arm,)*
+          _ => Err(#invalid_name(id)),
+        }
+      }
+
+      #(#group_items)*
+
+      /// 由 id 查标签名，未知 id 返回 `None`
+      pub fn label_name_of(id: #id_type) -> Option<&'static str> {
+        match id {
+          #(#id_to_name_arm,)*
+          _ => None,
+        }
+      }
+    }
+  };
+
+  // 运行时重新读取源文件，与编译期读到的内容比对，以发现两者不一致
+  let verify_source = args.verify_source.then(|| {
+    let test_name = Ident::new(
+      &format!("verify_{}_source", to_snake_case(&enum_name.to_string())),
+      Span::call_site(),
+    );
+    let files = sources.iter().flat_map(|source| &source.files);
+    let paths = files.clone().map(|(path, _)| path.display().to_string());
+    let contents = files.map(|(_, content)| content);
+    // 目录来源还须比较文件列表，否则新增或删除的标签文件不会被发现；
+    // 列出文件的规则与 `Source::read_dir` 一致：只取不以 `.` 开头的普通文件
+    let dirs = sources.iter().filter(|source| source.path.is_dir());
+    let dir_paths = dirs.clone().map(|source| source.path.display().to_string());
+    let dir_files = dirs.map(|source| {
+      let names = source.files.iter().map(|(path, _)| {
+        path
+          .file_name()
+          .map_or_else(String::new, |name| name.to_string_lossy().into_owned())
+      });
+      quote! { &[#(#names),*] }
+    });
+    quote! {
+      #[cfg(test)]
+      #[test]
+      fn #test_name() {
+        #(
+          let content = ::std::fs::read_to_string(#paths)
+            .unwrap_or_else(|e| panic!("Failed to read label file {}: {}", #paths, e));
+          assert!(
+            content == #contents,
+            "Label file {} has changed since {} was generated, rebuild to pick up the changes",
+            #paths,
+            stringify!(#enum_name),
+          );
+        )*
+        #(
+          let mut names: ::std::vec::Vec<::std::string::String> = ::std::fs::read_dir(#dir_paths)
+            .unwrap_or_else(|e| panic!("Failed to read label directory {}: {}", #dir_paths, e))
+            .map(|entry| entry.expect("Failed to read label directory entry").path())
+            .filter(|path| path.is_file())
+            .filter_map(|path| path.file_name().map(|name| name.to_string_lossy().into_owned()))
+            .filter(|name| !name.starts_with('.'))
+            .collect();
+          names.sort();
+          let expected: &[&str] = #dir_files;
+          assert!(
+            names == expected,
+            "Label directory {} now holds {:?} but {} was generated from {:?}, rebuild to pick up the changes",
+            #dir_paths,
+            names,
+            stringify!(#enum_name),
+            expected,
+          );
+        )*
+      }
+    }
+  });
+
+  let expanded = quote! {
+    #expanded
+    #verify_source
+  };
+
+  let expanded = match &args.module {
+    Some(module) => {
+      let module = Ident::new(module, Span::call_site());
+      let mod_vis = &input_ast.vis;
+      quote! {
+        #mod_vis mod #module {
+          #[allow(unused_imports)]
+          use super::*;
+
+          #expanded
+        }
+      }
+    }
+    None => expanded,
+  };
+
+  // 看这里
+  // 如果，你在看这份代码时，根本不知道代码在干啥，或者需要调试，
+  // 把下面这行代码取消注释，你就知道这份代生成了一个啥东西了
+  // println!("Generated enum:\n{}", expanded);
+  // 然后你就可以把生成的代码复制出来，放到 rust playground 里运行看看效果
+
+  Ok(expanded)
+}
+
+/// 检查派生宏所在枚举的变体与标签文件一致。
+///
+/// 每个标签须有同名的无字段变体，若写出判别值则须等于其 id；
+/// 非封闭模式下还须有 `Unknown(Id)` 变体，给出 `reserved` 时还须有 `Reserved(Id)` 变体，
+/// 此外不允许有多余的变体。
+fn check_variants(
+  args: &LabelArgs,
+  data: &syn::DataEnum,
+  labels: &[Label],
+) -> Result<(), syn::Error> {
+  // 这些参数作用于枚举定义本身，派生宏无法改写
+  let unsupported = [
+    ("module", args.module.is_some()),
+    ("vis", args.vis.is_some()),
+    ("non_exhaustive", args.non_exhaustive),
+    ("doc_table", args.doc_table),
+  ];
+  if let Some((name, _)) = unsupported.iter().find(|(_, set)| *set) {
+    return Err(syn::Error::new(
+      Span::call_site(),
+      format!("`{}` is not supported with #[derive(TomlLabel)]", name),
+    ));
+  }
+
+  let mut errors: Option<syn::Error> = None;
+  let mut push = |e: syn::Error| match &mut errors {
+    Some(errors) => errors.combine(e),
+    None => errors = Some(e),
+  };
+
+  for variant in &data.variants {
+    if !args.closed && variant.ident == "Unknown" {
+      if !matches!(&variant.fields, syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1) {
+        push(syn::Error::new_spanned(
+          variant,
+          "The `Unknown` variant must hold the label id, e.g. `Unknown(u32)`",
+        ));
+      }
+      continue;
+    }
+    if !args.reserved.is_empty() && variant.ident == "Reserved" {
+      if !matches!(&variant.fields, syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1) {
+        push(syn::Error::new_spanned(
+          variant,
+          "The `Reserved` variant must hold the label id, e.g. `Reserved(u32)`",
+        ));
+      }
+      continue;
+    }
+    let Some(label) = labels.iter().find(|label| label.ident == variant.ident) else {
+      push(syn::Error::new_spanned(
+        &variant.ident,
+        format!("Variant `{}` has no matching label", variant.ident),
+      ));
+      continue;
+    };
+    if !matches!(variant.fields, syn::Fields::Unit) {
+      push(syn::Error::new_spanned(
+        variant,
+        format!("Variant `{}` must not have fields", variant.ident),
+      ));
+    }
+    if let Some((_, discriminant)) = &variant.discriminant
+      && discriminant_value(discriminant) != Some(label.id)
+    {
+      push(syn::Error::new_spanned(
+        discriminant,
+        format!(
+          "Discriminant of `{}` must equal its label id {}",
+          variant.ident, label.id
+        ),
+      ));
+    }
+  }
+
+  let missing: Vec<String> = labels
+    .iter()
+    .filter(|label| !data.variants.iter().any(|v| v.ident == label.ident))
+    .map(|label| format!("`{}`", label.ident))
+    .collect();
+  if !missing.is_empty() {
+    push(syn::Error::new(
+      Span::call_site(),
+      format!("Missing variants for labels: {}", missing.join(", ")),
+    ));
+  }
+  if !args.closed && !data.variants.iter().any(|v| v.ident == "Unknown") {
+    push(syn::Error::new(
+      Span::call_site(),
+      "Missing variant `Unknown(Id)`; add it or use `closed = true`",
+    ));
+  }
+
+  if !args.reserved.is_empty() && !data.variants.iter().any(|v| v.ident == "Reserved") {
+    push(syn::Error::new(
+      Span::call_site(),
+      "Missing variant `Reserved(Id)` required by `reserved`",
+    ));
+  }
+
+  match errors {
+    Some(errors) => Err(errors),
+    None => Ok(()),
+  }
+}
+
+/// 从 `start` 起按 `step`（`1` 或 `-1`）的方向查找第一个不是标签 id、也不在保留区间内的 id，
+/// 越过 `end` 时返回 `None`
+fn unused_id(
+  labels: &[Label],
+  reserved: &[(i64, i64)],
+  start: i64,
+  end: i64,
+  step: i64,
+) -> Option<i64> {
+  let mut id = start;
+  while (step > 0 && id <= end) || (step < 0 && id >= end) {
+    if let Some(&(low, high)) = reserved
+      .iter()
+      .find(|(low, high)| (*low..=*high).contains(&id))
+    {
+      id = if step > 0 {
+        high.checked_add(1)?
+      } else {
+        low.checked_sub(1)?
+      };
+    } else if labels.iter().any(|label| label.id == id) {
+      id = id.checked_add(step)?;
+    } else {
+      return Some(id);
+    }
+  }
+  None
+}
+
+/// 整数字面量（可带负号）形式的判别值
+fn discriminant_value(expr: &syn::Expr) -> Option<i64> {
+  match expr {
+    syn::Expr::Lit(syn::ExprLit {
+      lit: syn::Lit::Int(lit),
+      ..
+    }) => lit.base10_parse().ok(),
+    syn::Expr::Unary(syn::ExprUnary {
+      op: syn::UnOp::Neg(_),
+      expr,
+      ..
+    }) => discriminant_value(expr).map(|v| -v),
+    _ => None,
+  }
+}
+
+fn to_snake_case(s: &str) -> String {
+  let mut out = String::new();
+  for (i, c) in s.chars().enumerate() {
+    if c.is_uppercase() {
+      if i > 0 {
+        out.push('_');
+      }
+      out.extend(c.to_lowercase());
+    } else {
+      out.push(c);
+    }
+  }
+  out
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// FNV-1a 64 位哈希，`stable_hash` 的取值，生成代码中对 `Unknown` 的计算须与之一致
+fn fnv1a(bytes: &[u8]) -> u64 {
+  bytes.iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+    (hash ^ *byte as u64).wrapping_mul(FNV_PRIME)
+  })
+}
+
+/// `from_label_str` 匹配用的 `(规范化后的名称, 变体名, 原始名称或别名)`
+type NameKey<'a> = (String, &'a Ident, &'a str);
+
+/// 按键分组，组按键升序排列，组内保持原有顺序
+fn group_by<T, K: Ord + Copy>(
+  items: impl IntoIterator<Item = T>,
+  key: impl Fn(&T) -> K,
+) -> Vec<(K, Vec<T>)> {
+  let mut groups: Vec<(K, Vec<T>)> = Vec::new();
+  for item in items {
+    let k = key(&item);
+    match groups.iter_mut().find(|(other, _)| *other == k) {
+      Some((_, group)) => group.push(item),
+      None => groups.push((k, vec![item])),
+    }
+  }
+  groups.sort_by_key(|(k, _)| *k);
+  groups
+}
+
+/// `lenient_parse` 下对标签名的规范化：去除首尾空白并转为小写，须与生成代码中对输入的处理一致
+fn normalize_name(name: &str) -> String {
+  name.trim().to_lowercase()
+}
+
+/// 分组名转为蛇形命名，用于生成常量名与方法名，如 `big cat` → `big_cat`
+fn group_snake_case(group: &str) -> Result<String, syn::Error> {
+  let snake: String = group
+    .chars()
+    .map(|c| {
+      if c.is_alphanumeric() {
+        c.to_ascii_lowercase()
+      } else {
+        '_'
+      }
+    })
+    .collect();
+  if syn::parse_str::<Ident>(&format!("is_{}", snake)).is_err()
+    || snake.starts_with(|c: char| c.is_numeric())
+  {
+    return Err(syn::Error::new(
+      Span::call_site(),
+      format!("Group `{}` cannot be turned into an item name", group),
+    ));
+  }
+  Ok(snake)
+}
+
+/// 从标签文件中读出的一个标签
+struct Label {
+  /// 标签文件中的原始名称
+  name: String,
+  /// 对应的枚举变体名
+  ident: Ident,
+  /// 标签 id
+  id: i64,
+  /// 带类型后缀的 id 字面量
+  lit: Literal,
+  /// 标签文件中的原始 id，仅在 `densify` 时与 `id` 不同
+  original: i64,
+  /// 弃用说明
+  deprecated: Option<String>,
+  /// 展示名
+  display: Option<String>,
+  /// 所属的分组
+  groups: Vec<String>,
+  /// 展示顺序
+  sort_order: Option<i64>,
+  /// 别名
+  aliases: Vec<String>,
+  /// 标签所在的文件，用于报错
+  path: PathBuf,
+}
+
+/// 按 `strip_prefix` / `strip_delimiter` 去掉标签名中的命名空间部分，仅用于生成变体名，
+/// 如 `coco/person` 在 `strip_delimiter = "/"` 下得到 `person`
+fn strip_namespace<'a>(args: &LabelArgs, name: &'a str) -> &'a str {
+  let mut name = name;
+  if let Some(prefix) = &args.strip_prefix {
+    name = name.strip_prefix(prefix.as_str()).unwrap_or(name);
+  }
+  if let Some(delimiter) = &args.strip_delimiter
+    && let Some((_, rest)) = name.rsplit_once(delimiter.as_str())
+  {
+    name = rest;
+  }
+  name
+}
+
+/// 由标签名生成变体名。
+///
+/// 字母、数字、下划线与空格以外的字符（如 `::`、`/`、`+`）替换为 `_`，
+/// 以数字开头时补一个前导 `_`，再按空格分词转为驼峰。原始名称仍由 `to_label_str` 返回。
+fn variant_ident(name: &str) -> Result<Ident, syn::Error> {
+  let sanitized: String = name
+    .chars()
+    .map(|c| {
+      if c.is_alphanumeric() || c == '_' || c == ' ' {
+        c
+      } else {
+        '_'
+      }
+    })
+    .collect();
+  let mut ident = to_camel_case(&sanitized);
+  if ident.starts_with(|c: char| c.is_numeric()) {
+    ident.insert(0, '_');
+  }
+  syn::parse_str::<Ident>(&ident).map_err(|_| {
+    syn::Error::new(
+      Span::call_site(),
+      format!(
+        "Label `{}` cannot be turned into a variant name (got `{}`)",
+        name, ident
+      ),
+    )
+  })
+}
+
+fn to_camel_case(s: &str) -> String {
+  s.split(' ')
+    .map(|word| {
+      let mut c = word.chars();
+      match c.next() {
+        None => String::new(),
+        Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
+      }
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// 以属性宏形式展开，返回生成的枚举定义
+  fn expand_enum(args: LabelArgs, input: DeriveInput) -> syn::ItemEnum {
+    let tokens = expand(&args, &input, Form::Attribute).unwrap();
+    let file: syn::File = syn::parse2(tokens).unwrap();
+    file
+      .items
+      .into_iter()
+      .find_map(|item| match item {
+        syn::Item::Enum(item) if item.ident == input.ident => Some(item),
+        _ => None,
+      })
+      .unwrap()
+  }
+
+  /// 枚举上 `#[doc = "..."]` 的内容
+  fn doc_lines(item: &syn::ItemEnum) -> Vec<String> {
+    item
+      .attrs
+      .iter()
+      .filter_map(|attr| match &attr.meta {
+        syn::Meta::NameValue(meta) if meta.path.is_ident("doc") => match &meta.value {
+          syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(doc),
+            ..
+          }) => Some(doc.value()),
+          _ => None,
+        },
+        _ => None,
+      })
+      .collect()
+  }
+
+  #[test]
+  fn doc_table_snapshot() {
+    let item = expand_enum(
+      syn::parse_quote!(file = "tests/labels/labels.toml", doc_table = true),
+      syn::parse_quote!(
+        enum Label {}
+      ),
+    );
+    assert_eq!(
+      doc_lines(&item),
+      [
+        " | 标签 | 变体 | id |",
+        " | --- | --- | --- |",
+        " | `cat` | `Cat` | 0 |",
+        " | `dog` | `Dog` | 1 |",
+        " | `big bird` | `BigBird` | 5 |",
+      ]
+    );
+  }
+
+  #[test]
+  fn doc_table_is_off_by_default() {
+    let item = expand_enum(
+      syn::parse_quote!(file = "tests/labels/labels.toml"),
+      syn::parse_quote!(
+        enum Label {}
+      ),
+    );
+    assert!(doc_lines(&item).is_empty());
+  }
+
+  #[test]
+  fn doc_table_escapes_pipes() {
+    let item = expand_enum(
+      syn::parse_quote!(file = "tests/labels/pipe.toml", doc_table = true),
+      syn::parse_quote!(
+        enum Label {}
+      ),
+    );
+    assert_eq!(doc_lines(&item)[2], " | `a\\|b` | `A_b` | 0 |");
+  }
+}
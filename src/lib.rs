@@ -10,21 +10,31 @@
 
 use proc_macro::TokenStream;
 use proc_macro2::Span;
-use quote::quote;
-use std::collections::HashMap;
-use std::fs;
-use syn::{DeriveInput, Ident, parse_macro_input};
+use std::collections::BTreeSet;
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::{DeriveInput, LitStr, Token, parse_macro_input};
+
+mod args;
+mod emit;
+mod expand;
+mod source;
+
+use args::LabelArgs;
+use expand::{Form, expand};
+use source::Source;
 
 ///
 /// 一个根据 TOML 文件生成标签枚举的宏。
-/// 参数格式：`file = "path/to/labels.toml"`
+/// 参数格式：`file = "path/to/labels.toml"`，多个参数以逗号分隔，容许结尾多余的逗号，
+/// 字符串参数须加引号，布尔参数写 `true` / `false`。
 /// 如果 toml 文件是这样的：
 /// ```toml
 /// cat = 0
 /// dog = 1
 /// ```
 /// 然后代码是
-/// ```rust
+/// ```rust,ignore
 /// #[toml_label(file = "labels.toml")]
 /// pub enum MyLabel;
 /// ```
@@ -36,155 +46,313 @@ use syn::{DeriveInput, Ident, parse_macro_input};
 /// }
 /// ```
 ///
+/// 此外还会在枚举上生成以下关联项（`Id` 为 id 的类型，默认 `u32`，见 `repr` 参数）：
+/// - `ID_TO_NAME: &[(Id, &str)]`，按 id 升序；
+/// - `NAME_TO_ID: &[(&str, Id)]`，按名称（字节序）升序，
+///   这两张常量表便于在 `const fn` 中二分查找；
+/// - `from_label_str(&str) -> Option<Self>`：由标签名构造标签；
+/// - `parse(&str) -> Option<Self>`：先按标签名解析，失败时再把字符串当作整数 id 解析，
+///   `"cat"` 与 `"0"` 均可；封闭模式下未知 id 返回 `None`；
+/// - `canonical_key(&self) -> &'static str`：标签文件中的原始键，无论是否设置展示名都不变；
+///   `display_name(&self) -> &'static str`：内联表中 `display` 给出的展示名，未设置时即原始键
+///   （两者对 `Unknown` 都返回 `"unknown"`）。`to_label_str` 始终返回原始键；
+/// - `stable_hash(&self) -> u64`：原始键的 FNV-1a 64 位哈希，在宏展开时算出，
+///   与本地 id 分配无关，可作为跨进程、跨分片的稳定标识；
+/// - `label_id_of(&str) -> Option<Id>` 与 `label_name_of(Id) -> Option<&str>`，
+///   无需构造枚举即可在名称与 id 之间互转；两者是枚举的关联函数（`MyLabel::label_id_of`）
+///   而非自由函数，以免同一模块中的多个标签枚举生成同名函数而冲突；
+/// - `const fn id_of(&str) -> Option<Id>`：`label_id_of` 的 `const` 版本，
+///   如 `const CAT: Option<u32> = MyLabel::id_of("cat");`，可在编译期校验配置中的标签名；
+/// - `index(&self) -> Option<usize>` 与 `from_index(usize) -> Option<Self>`：稠密下标，
+///   即标签按 id 升序排列时的位置（`0..LABEL_NUM`），`Unknown` 没有稠密下标；
+/// - `N: usize`：标签个数（即 `LABEL_NUM`，但为 `usize`），可直接作为常量泛型实参，
+///   如 `struct Model<const N: usize>` 写作 `Model<{ MyLabel::N }>`，并可用于
+///   `[f32; MyLabel::N]` 等数组长度。变体 `N` 会遮蔽该常量，因此映射为变体 `N` 的标签名
+///   （如 `n`）报错；
+/// - `ALL: &[Self]`：按 id 升序的所有已知标签（不含 `Unknown`），以及 `iter()` 与
+///   `iter_where(fn(&Self) -> bool)`，后者只遍历满足条件的标签，如
+///   `MyLabel::iter_where(MyLabel::is_animal)`；
+/// - `all_names() -> &'static [&'static str]`：按 id 升序的所有原始键，在宏展开时生成，
+///   无需逐个调用 `to_label_str`；
+/// - `DICTIONARY: &[&str]`，按稠密下标排列的标签名，可直接用作 Arrow
+///   `DictionaryArray` 的字典，`index()` 即对应的字典键；
+/// - `INDEX_TO_ID: [Id; LABEL_NUM]`，稠密下标到 id 的查找表，可用于把模型输出下标
+///   转回语义 id；
+/// - `to_onehot(&self) -> [f32; OUTPUT_DIM]`：独热向量，维度的含义随 `output_dim` 参数
+///   （稠密下标或 id），`Unknown` 得到全零向量；
+/// - `from_logits(&[f32]) -> Result<Self, {枚举名}LogitsError>`：取模型输出的最大值所在
+///   维度作为标签（维度的含义同 `to_onehot`），长度不等于 `OUTPUT_DIM`、含 NaN 或该维度
+///   没有标签时返回错误；错误类型随枚举一同生成，实现了 `Display` 与 `std::error::Error`；
+/// - `next(&self)` 与 `prev(&self)`：按 id 顺序前后移动，到达两端时返回 `None`；
+/// - `try_from_label_id(Id) -> Result<Self, Invalid{枚举名}>`：未知 id 返回错误而不是
+///   `Unknown`，错误类型 `Invalid{枚举名}(pub Id)` 随枚举一同生成，实现了 `Display` 与
+///   `std::error::Error`，适用于来自网络等不可信来源的 id（`try_from = true` 时另实现
+///   与之等价的 `TryFrom<Id>`）；
+/// - `from_c_int(i32) -> Self`：FFI 边界的适配，在 id 类型范围内的值（有符号 id 类型时
+///   包括负数）同 `from_label_id`；超出范围的值返回 `Unknown`，其中的 id 是展开时从类型
+///   的最小值（下溢）或最大值（上溢，设置了 `unknown_above = K` 时为 `K`）起选出的、
+///   不属于任何标签也不在保留区间内的值，因此不会被误当作真实标签。`u32` 与 `u64` 比
+///   `i32` 宽，下溢的哨兵改从类型的最大值往下选（`u64` 即 `u64::MAX`），与任何范围内的
+///   输入得到的 `Unknown` 都不同。封闭模式下没有
+///   `Unknown`，或标签与保留区间占满了 id 类型、选不出这样的值时，不生成该方法；
+/// - `{枚举名}Indexed<T>(pub [T; LABEL_NUM])`：按稠密下标存放每个标签的数据，
+///   实现了 `Index<枚举>` 与 `IndexMut<枚举>`，可写作 `stats[MyLabel::Cat]`
+///   （以 `Unknown` 作下标时 panic，`get` / `get_mut` 则返回 `None`）。
+///
+/// 给出 `from_json = true` 时还会生成 `from_json(&serde_json::Value) -> Option<Self>`：
+/// JSON 整数按 id 解析（同 `from_label_id`，封闭模式下未知 id 返回 `None`），字符串按标签名
+/// 解析（同 `from_label_str`），其他值返回 `None`。使用方须自行依赖 `serde_json`。
+///
+/// 给出 `name_set = true` 时还会生成 `NAME_SET: phf::Set<&'static str>`（所有原始标签名
+/// 及别名的完美哈希集合）与 `is_known_name(&str) -> bool`，无需构造枚举即可 O(1) 判断名称是否已知；
+/// 两者与 `from_label_str` 一致，`lenient_parse` 时集合中为规范化后的名称，输入也先规范化。
+/// 使用方须自行依赖带 `macros` 特性的 `phf`。
+///
+/// 可选参数：
+/// - `repr = "u8" | "u16" | "u32" | "u64" | "i8" | "i16" | "i32" | "i64"`：为枚举加上对应的
+///   `#[repr]`，并以该类型作为 id 的类型（默认 `u32`）。生成代码中的 id 字面量均带有该类型
+///   后缀，`to_label_id`、`from_label_id`、`try_from_label_id`、`Unknown` 以及上述常量表都
+///   使用该类型，因此所实现 trait 的签名须与之一致；`LABEL_NUM` 仍为 `u32`。
+///   标签 id 超出该类型范围（包括无符号类型下的负 id）时报错，错误中一次列出所有越界的
+///   标签；TOML 整数为 `i64`，因此 `u64` 的 id 不能超过 `i64::MAX`。
+///   枚举上的常量 `REPR_BYTES: usize` 为该类型的字节数（1、2、4 或 8），
+///   便于嵌入式代码按 id 宽度分配缓冲区。
+/// - `id_type = "u64"` 等：与 `repr` 取值相同，只改变 id 的类型而不加 `#[repr]`；
+///   与 `repr` 同时给出时两者须一致。
+/// - `copy = false`：不派生 `Copy`（仍派生 `Clone`）。
+/// - `module = "labels"`：把生成的枚举及其实现包裹进 `mod labels { ... }`，
+///   模块沿用原枚举的可见性，枚举在模块内为 `pub`；模块内会 `use super::*`，
+///   因此 `WithLabel` 等名称按外层作用域解析。
+/// - `output_dim = "dense" | "max_id"`：`OUTPUT_DIM` 的计算方式，默认 `dense`
+///   （等于 `LABEL_NUM`）；`max_id` 时等于 `MAX_LABEL_ID + 1`，适用于 id 稀疏的布局。
+/// - `verify_source = true`：额外生成一个 `#[cfg(test)]` 测试 `verify_<枚举名>_source`，
+///   运行时按绝对路径重新读取标签文件，若内容与编译时不同则失败，用于在 CI 中发现
+///   文件已修改而生成代码未重新生成的情况。与 `dir` 同用时还会比较目录中的文件列表，
+///   新增或删除标签文件同样会使测试失败。
+/// - `try_from = true`：为枚举实现 `TryFrom<Id>`（`Error` 为 `Invalid{枚举名}`），
+///   `MyLabel::try_from(id)` 与 `try_from_label_id(id)` 等价。默认不实现，因为它与使用方
+///   为枚举编写的 `impl From<Id>` 冲突（E0119）。
+/// - `closed = true`：封闭模式，不生成 `Unknown` 变体，枚举为无字段枚举，
+///   每个变体的判别值等于其 id，并带上 `#[repr(Id)]`，因此 `MyLabel::Cat as u32`
+///   与 `to_label_id()` 一致。此时 `from_label_id` 遇到未知 id 会 panic。
+///   属性宏形式下（且未设 `copy = false`）还会实现按 id 排序的 `PartialOrd` 与 `Ord`，
+///   比较时直接把枚举转为 id 整数，无需 `match`。
+/// - `optimize = true`：`from_label_str` 生成为分级的 `match`，先按名称的字节长度分派，
+///   长度相同的再按最后一个字节分派，最后只与同一分支中的少数标签名精确比较，
+///   减少逐个比较的次数，适用于中等规模的标签集，无需引入 phf 等依赖。
+/// - `strip_prefix = "coco/"`、`strip_delimiter = "/"`：生成变体名前去掉标签名中的命名空间，
+///   前者去掉开头的固定前缀（不以其开头的标签名不变），后者去掉最后一个分隔符及其之前的
+///   部分，如 `coco/person` → `Person`、`v2:car`（`strip_delimiter = ":"`）→ `Car`；
+///   两者同用时先去前缀。`to_label_str` 等仍使用完整的原始名称，去掉后变体名冲突时报错。
+/// - `lenient_parse = true`：`from_label_str` 先去除输入的首尾空白并转为小写再匹配，
+///   标签名也按同样方式规范化，因此 `" CAT "` 可解析为 `Cat`；规范化后相同的两个标签名报错。
+/// - `doc_table = true`：在枚举的文档中生成一张列出所有标签名、变体名与 id 的表格，
+///   无需打开标签文件即可在 rustdoc 中查看；标签很多时会使文档变长，因此默认不生成。
+/// - `non_exhaustive = true`：为枚举加上 `#[non_exhaustive]`，下游 crate 的 `match`
+///   须带通配分支，之后新增标签不会破坏下游代码。与 `Unknown` 兜底变体一起，
+///   使向前兼容成为显式约定。
+/// - `unknown_above = K`：`from_label_id` 把所有不小于 `K` 的 id 归入同一个 `Unknown(K)`，
+///   而不是保留原 id，适用于阈值以上都算"其他"的数据集；标签 id 不能不小于 `K`，
+///   也不能与 `closed = true` 同用。
+/// - `reserved = [[100, 199], [300, 300]]`：声明保留的 id 闭区间，枚举上额外生成
+///   `Reserved(Id)` 变体，`from_label_id` 把区间内的 id 构造为 `Reserved(id)` 而非
+///   `Unknown(id)`，`try_from_label_id` 对其返回错误；`to_label_str` 为 `"reserved{id}"`，
+///   区间另以常量 `RESERVED_IDS: &[(Id, Id)]` 给出。标签 id 不能落在保留区间内，
+///   派生宏形式下须手写 `Reserved(Id)` 变体，不能与 `closed = true` 同用。
+/// - `densify = true`：按 id 升序把各标签的 id 重新分配为 `0..LABEL_NUM`，`to_label_id`、
+///   `from_label_id` 以及各常量表都使用新的连续 id，标签文件中的原 id 由额外生成的
+///   `original_id(&self) -> Id` 给出，适用于数据集 id 稀疏而模型需要连续 id 的情况。
+/// - `map_ids_from = "path::to::REMAP"`：`from_label_id`（以及 `from_json`）先把传入的外部 id
+///   用作下标查 `REMAP`，取得标签 id 后再构造标签，适用于模型训练时的 id 顺序与标签文件不同
+///   的情况。`REMAP` 须是元素类型为 id 类型的常量切片或数组，并且在宏调用处可见（使用
+///   `module` 时按外层作用域解析）；表外的 id 视为未知 id，封闭模式下 panic
+///   （`parse` 与 `from_json` 则返回 `None`）。`try_from_label_id` 与 `TryFrom`
+///   接受的是标签 id，不经过映射。
+/// - `trait = "path::to::Trait"`：实现指定的 trait 而非 `WithLabel`；
+///   `trait = "none"` 时不实现任何 trait，`LABEL_NUM`、`from_label_id`、`to_label_str`、
+///   `to_label_id` 改为枚举的固有项，使用时无需引入 trait。
+/// - `trait_path = "::my_core::labels::WithLabel"`：与 `trait` 相同，但只接受 trait 路径，
+///   用于 trait 由共享 crate 在不易猜到的路径下再导出的情形：生成的 `impl` 直接使用该完整
+///   路径，宏调用处无需为此 `use` 该 trait。
+/// - `traits = ["WithLabel", "crate::ClassId"]`：同时实现多个 trait，每个 trait 都得到
+///   同一组项（名称可按下一条改写），因此这些 trait 须具有相同的项与签名；
+///   `traits = []` 等同于 `trait = "none"`。`trait`、`trait_path` 与 `traits` 只能给出其一。
+/// - `num_const`、`from_id_method`、`name_method`、`id_method`：分别重命名
+///   `LABEL_NUM`、`from_label_id`、`to_label_str`、`to_label_id`，以匹配已有 trait 的
+///   项名，如 `id_method = "class_id"`；设为 `"none"` 时不生成该项。
+/// - `vis = "pub(crate)"`：覆盖生成枚举的可见性；与 `module` 同用时作用于模块内的枚举
+///   （模块本身仍沿用原枚举的可见性）。
+/// - `emit_json = "labels.json"`：展开时把合并后的标签表以 JSON 对象 `{"名称": id, ...}`
+///   写出，供前端等非 Rust 工具使用同一份数据。宏在编译期写文件并不常见，因此只允许写入
+///   构建脚本的 `OUT_DIR`：路径相对于 `OUT_DIR` 解析，不能是绝对路径或包含 `..`，
+///   crate 须有 `build.rs`（可以为空）才会设置 `OUT_DIR`。文件只在增量编译重新展开宏时更新。
+/// - `dir = "labels/"`：代替 `file`，从目录读取标签，目录中每个文件是一个标签，
+///   去掉扩展名的文件名为标签名，文件内容为 id（如文件 `cat.txt` 内容为 `0`）；
+///   以 `.` 开头的隐藏文件与子目录被忽略，相对路径同样相对于 `CARGO_MANIFEST_DIR`。
+///   与 TOML 一致，文件中以 `#` 开头的注释行与空行被忽略。
+/// - `out_dir_file = "labels.toml"`：代替 `file`，读取构建脚本写入 `OUT_DIR` 的标签文件，
+///   路径相对于 `OUT_DIR` 解析，不能是绝对路径或包含 `..`。适用于标签表由 `build.rs`
+///   从环境变量、下载的模型元数据等生成的情形：`build.rs` 写出
+///   `Path::new(&env::var("OUT_DIR")?).join("labels.toml")`，宏即可按固定的相对路径读取。
+///   路径为目录时按 `dir` 的规则读取。
+/// - `include = "base.toml"`：合并另一个标签文件，可重复出现（其余参数重复给出时报错）。被包含的文件先于主文件读取；
+///   名称与 id 都相同的条目视为同一标签，同名不同 id 或同 id 不同名时报错。
+///
+/// 变体名由标签名按空格分词转为驼峰得到；`::`、`/`、`+` 等不能出现在标识符中的字符
+/// 替换为 `_`，以数字开头时补前导 `_`（如 `a+b` → `A_b`，`3d box` → `_3dBox`），
+/// `to_label_str` 仍返回原始名称。不同标签清洗后得到同一变体名时报错。
+///
+/// id 也可以写成数字字符串（如 `cat = "0"`），以兼容给所有值加引号的生成工具，
+/// 无法解析为整数的字符串仍会报错。
+///
+/// 标签也可以写成内联表 `old_cat = { id = 3, deprecated = "use cat instead" }`，
+/// 其中 `deprecated` 会在对应变体上生成 `#[deprecated(note = "...")]`，使用处得到弃用警告，
+/// 变体及各转换方法照常工作。派生宏形式下弃用属性需手写在变体上。
+/// 内联表可以省略 `id`（如 `dog = {}`），此时按文件中的顺序取目前为止最大 id 加一，
+/// 若之后显式写出的 id 与自动分配的 id 相同则报错。例如依次写
+/// `cat = 0`、`dog = {}`、`bird = 5`、`fish = {}`，得到的 id 为 0、1、5、6。
+/// 内联表中的 `group = "animal"`（或数组 `group = ["animal", "pet"]`）把标签归入分组，
+/// 每个分组在枚举上生成常量表 `ANIMALS: &[Self]` 与只含一次 `match` 的 `is_animal(&self)`，
+/// 无需分配即可在热路径上判断成员关系（分组名中的非字母数字字符替换为 `_`）。
+/// 内联表中的 `alias = "kitty"`（或数组 `alias = ["kitty", "puss"]`）为标签添加别名，
+/// `from_label_str`（及 `parse`）同样接受别名，`to_label_str` 仍返回原始键；
+/// `label_id_of`、`id_of` 与 `NAME_TO_ID` 只含原始键。别名与任一标签的原始键或其他别名
+/// 相同时报错，并指出冲突的两处，保证由名称到标签的映射无歧义。
+/// 内联表中的 `sort_order = 3` 给出展示顺序，`iter_display_order()` 按它遍历标签，
+/// 未设置的标签以 id 参与排序，相同时按 id；存储的 id 与 `to_label_id` 不受影响。
+///
+/// 标签文件也可以用两个平行数组给出标签，`names` 与 `ids` 按位置一一对应，长度须相同：
+/// ```toml
+/// names = ["cat", "dog"]
+/// ids = [0, 1]
+/// ```
+///
+/// 标签文件中的 `version`（或 `schema_version`）是保留键，不会生成变体，
+/// 其值作为 `SCHEMA_VERSION: u32` 常量生成在枚举上；使用 `include` 时取主文件中的版本。
+///
+/// 文件路径为相对路径时，相对于 `CARGO_MANIFEST_DIR` 解析。
+/// 同一标签文件内也不允许两个名称共用一个 id。
+///
 #[proc_macro_attribute]
 pub fn toml_label(args: TokenStream, input: TokenStream) -> TokenStream {
-  let args_str = args.to_string();
-  let parts: Vec<&str> = args_str.split(',').collect();
-
-  if parts.len() != 1 {
-    return syn::Error::new(Span::call_site(), "Expected format: file = \"path\"")
-      .to_compile_error()
-      .into();
-  }
-
-  let file_arg = parts[0].trim();
-
-  if !file_arg.trim().starts_with("file") || !file_arg.contains("=") {
-    return syn::Error::new(
-      proc_macro2::Span::call_site(),
-      "First argument must be file path",
-    )
-    .to_compile_error()
-    .into();
-  }
-
-  let file_path = { file_arg.split('=').nth(1).unwrap().trim().trim_matches('"') };
-
-  let toml_content = match fs::read_to_string(file_path) {
-    Ok(content) => content,
-    Err(e) => {
-      return syn::Error::new(
-        proc_macro2::Span::call_site(),
-        format!("Failed to read file {}: {}", file_path, e),
-      )
-      .to_compile_error()
-      .into();
-    }
-  };
+  let args = parse_macro_input!(args as LabelArgs);
+  let input_ast = parse_macro_input!(input as DeriveInput);
+  expand(&args, &input_ast, Form::Attribute)
+    .unwrap_or_else(|e| e.to_compile_error())
+    .into()
+}
 
-  let toml_data = match toml::from_str(&toml_content) {
-    Ok(data) => {
-      let data: HashMap<String, u32> = data;
-      let mut data: Vec<(String, u32)> = data.into_iter().collect();
-      data.sort_by_key(|(_, id)| *id);
-      data
-    }
-    Err(e) => {
-      return syn::Error::new(
-        proc_macro2::Span::call_site(),
-        format!("Failed to parse TOML file: {}", e),
-      )
+///
+/// `toml_label` 的函数式宏形式，枚举名由 `name` 参数给出，其余参数与属性宏相同；
+/// 没有原枚举可以沿用可见性，默认为私有，可用 `vis` 参数指定：
+/// ```rust,ignore
+/// toml_label_enum!(name = "MyLabel", file = "labels.toml", vis = "pub(crate)");
+/// ```
+///
+#[proc_macro]
+pub fn toml_label_enum(args: TokenStream) -> TokenStream {
+  let args = parse_macro_input!(args as LabelArgs);
+  let Some(name) = &args.name else {
+    return syn::Error::new(Span::call_site(), "Missing argument: name = \"EnumName\"")
       .to_compile_error()
       .into();
-    }
   };
+  let input_ast: DeriveInput = syn::parse_quote! { enum #name {} };
+  expand(&args, &input_ast, Form::Function)
+    .unwrap_or_else(|e| e.to_compile_error())
+    .into()
+}
 
+///
+/// `toml_label` 的派生宏形式，参数写在辅助属性 `#[toml_label(...)]` 中：
+/// ```rust,ignore
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, TomlLabel)]
+/// #[toml_label(file = "labels.toml")]
+/// pub enum MyLabel {
+///   Cat,
+///   Dog,
+///   Unknown(u32),
+/// }
+/// ```
+/// 派生宏不能改写枚举，因此变体需要手写，宏会检查它们与标签文件一致
+/// （每个标签一个同名无字段变体，非封闭模式下另有 `Unknown(Id)`），
+/// 然后生成与属性宏相同的 trait 实现与关联项。`derive` 列表与 `#[repr]` 由使用者自行
+/// 书写，`copy`、`module`、`vis`、`non_exhaustive` 与 `doc_table` 参数在此不适用。
+///
+#[proc_macro_derive(TomlLabel, attributes(toml_label))]
+pub fn derive_toml_label(input: TokenStream) -> TokenStream {
   let input_ast = parse_macro_input!(input as DeriveInput);
-  let enum_name = &input_ast.ident;
-
-  // 检查是否是枚举
-  match input_ast.data {
-    syn::Data::Enum(_) => {}
-    _ => {
-      return TokenStream::from(quote! {
-          compile_error!("This macro can only be used on enums");
-      });
-    }
-  }
-
-  let pairs: Vec<_> = toml_data
-    .into_iter()
-    .map(|(name, id)| {
-      let ident = Ident::new(&to_camel_case(&name), Span::call_site());
-      (ident, id, name)
-    })
-    .collect();
-
-  let enum_vars = pairs.iter().map(|(ident, _, _)| {
-    quote! {
-      #ident
-    }
-  });
-
-  let vars_id = pairs.iter().map(|(ident, id, _)| {
-    quote! {
-      #id => #enum_name::#ident
-    }
-  });
-
-  let label_name = pairs.iter().map(|(ident, _, name)| {
-    quote! {
-      #enum_name::#ident => String::from(#name)
-    }
-  });
-
-  let label_id = pairs.iter().map(|(ident, id, _)| {
-    quote! {
-      #enum_name::#ident => #id
-    }
-  });
+  derive_args(&input_ast)
+    .and_then(|args| expand(&args, &input_ast, Form::Derive))
+    .unwrap_or_else(|e| e.to_compile_error())
+    .into()
+}
 
-  let vis = &input_ast.vis;
+/// 从辅助属性 `#[toml_label(...)]` 中读取参数
+fn derive_args(input: &DeriveInput) -> Result<LabelArgs, syn::Error> {
+  let attr = input
+    .attrs
+    .iter()
+    .find(|attr| attr.path().is_ident("toml_label"))
+    .ok_or_else(|| {
+      syn::Error::new(
+        Span::call_site(),
+        "#[derive(TomlLabel)] requires a #[toml_label(file = \"...\")] attribute",
+      )
+    })?;
+  attr.parse_args()
+}
 
-  let label_num = pairs.len() as u32;
+///
+/// 在编译期检查两个标签文件（或目录）的标签名集合是否相同，忽略 id：
+/// ```rust,ignore
+/// assert_labels_match!("labels/v1.toml", "labels/v2.toml");
+/// ```
+/// 集合相同时不展开任何代码，否则报错并列出只出现在其中一侧的标签名。
+/// 用于在多个枚举之间互相转换时尽早发现两份标签文件已经分叉；路径解析方式与 `file` 相同。
+///
+#[proc_macro]
+pub fn assert_labels_match(input: TokenStream) -> TokenStream {
+  labels_match(input.into())
+    .map(|_| TokenStream::new())
+    .unwrap_or_else(|e| e.to_compile_error().into())
+}
 
-  let expanded = quote! {
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-    #vis enum #enum_name {
-        #(#enum_vars,)*
-        Unknown(u32),
-    }
+/// 比较两个标签来源的标签名集合
+fn labels_match(input: proc_macro2::TokenStream) -> Result<(), syn::Error> {
+  let paths = Punctuated::<LitStr, Token![,]>::parse_terminated.parse2(input)?;
+  let [a, b] = <[&LitStr; 2]>::try_from(paths.iter().collect::<Vec<_>>()).map_err(|_| {
+    syn::Error::new(
+      Span::call_site(),
+      "assert_labels_match! expects two paths: assert_labels_match!(\"a.toml\", \"b.toml\")",
+    )
+  })?;
 
-    impl WithLabel for #enum_name {
-      const LABEL_NUM: u32 = #label_num;
-      fn from_label_id(label_id: u32) -> Self {
-        match label_id {
-          #(#vars_id,)*
-          i => #enum_name::Unknown(i),
-        }
-      }
-      fn to_label_str(&self) -> String {
-        match self {
-          #(#label_name,)*
-          #enum_name::Unknown(i) => format!("unknown{}", i),
-        }
-      }
-      fn to_label_id(&self) -> u32 {
-        match self {
-          #(#label_id, )*
-          #enum_name::Unknown(i) => *i,
-        }
-      }
-    }
+  let names = |path: &LitStr| {
+    Source::read_any(&path.value()).map(|source| {
+      source
+        .entries
+        .into_iter()
+        .map(|entry| entry.name)
+        .collect::<BTreeSet<_>>()
+    })
   };
+  let (names_a, names_b) = (names(a)?, names(b)?);
+  if names_a == names_b {
+    return Ok(());
+  }
 
-  // 看这里
-  // 如果，你在看这份代码时，根本不知道代码在干啥，或者需要调试，
-  // 把下面这行代码取消注释，你就知道这份代生成了一个啥东西了
-  // println!("Generated enum:\n{}", expanded);
-  // 然后你就可以把生成的代码复制出来，放到 rust playground 里运行看看效果
-
-  TokenStream::from(expanded)
-}
-
-fn to_camel_case(s: &str) -> String {
-  s.split(' ')
-    .map(|word| {
-      let mut c = word.chars();
-      match c.next() {
-        None => String::new(),
-        Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
-      }
-    })
-    .collect()
+  let only = |x: &BTreeSet<String>, y: &BTreeSet<String>| {
+    x.difference(y)
+      .map(|name| format!("`{}`", name))
+      .collect::<Vec<_>>()
+      .join(", ")
+  };
+  let mut message = format!("Labels in {} and {} differ", a.value(), b.value());
+  for (path, names) in [(a, only(&names_a, &names_b)), (b, only(&names_b, &names_a))] {
+    if !names.is_empty() {
+      message.push_str(&format!("; only in {}: {}", path.value(), names));
+    }
+  }
+  Err(syn::Error::new(Span::call_site(), message))
 }
@@ -0,0 +1,687 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// src/source.rs - 标签文件的读取与合并
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use proc_macro2::Span;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// 一个已读取的标签文件（或每个文件一个标签的目录）
+pub(crate) struct Source {
+  /// 解析后的绝对路径
+  pub(crate) path: PathBuf,
+  /// 读取过的各个文件的路径与原始内容
+  pub(crate) files: Vec<(PathBuf, String)>,
+  /// 文件中的标签条目
+  pub(crate) entries: Vec<Entry>,
+  /// 保留键 `version` / `schema_version` 给出的模式版本
+  pub(crate) version: Option<u32>,
+}
+
+/// 标签文件中的一个条目，写作 `name = id` 或 `name = { id = ..., ... }`
+#[derive(Clone)]
+pub(crate) struct Entry {
+  pub(crate) name: String,
+  pub(crate) id: i64,
+  /// 弃用说明，生成 `#[deprecated(note = "...")]`
+  pub(crate) deprecated: Option<String>,
+  /// 展示名，写作 `display = "Big Bird"`
+  pub(crate) display: Option<String>,
+  /// 所属的分组，写作 `group = "animal"` 或 `group = ["animal", "pet"]`
+  pub(crate) groups: Vec<String>,
+  /// 展示顺序，写作 `sort_order = 3`，未设置时按 id 排列
+  pub(crate) sort_order: Option<i64>,
+  /// 别名，写作 `alias = "kitty"` 或 `alias = ["kitty", "puss"]`，`from_label_str` 同样接受
+  pub(crate) aliases: Vec<String>,
+  /// 条目所在的文件，用于报错
+  pub(crate) path: PathBuf,
+}
+
+/// 表示模式版本的保留键，不会作为标签
+const VERSION_KEYS: [&str; 2] = ["version", "schema_version"];
+
+impl Source {
+  pub(crate) fn read(path: &str) -> Result<Self, syn::Error> {
+    Self::read_path(resolve_path(path))
+  }
+
+  /// 读取构建脚本写入 `OUT_DIR` 的标签文件，路径为目录时同 `read_dir`
+  pub(crate) fn read_out_dir(path: &str) -> Result<Self, syn::Error> {
+    let path = out_dir_path("out_dir_file", path)?;
+    if path.is_dir() {
+      Self::read_dir_path(path)
+    } else {
+      Self::read_path(path)
+    }
+  }
+
+  fn read_path(path: PathBuf) -> Result<Self, syn::Error> {
+    let content = fs::read_to_string(&path).map_err(|e| {
+      syn::Error::new(
+        Span::call_site(),
+        format!("Failed to read file {}: {}", path.display(), e),
+      )
+    })?;
+    Self::parse(path, content)
+  }
+
+  /// 解析已读入的 TOML 标签文件内容，`path` 只用于错误信息与 `verify_source`
+  fn parse(path: PathBuf, content: String) -> Result<Self, syn::Error> {
+    let mut table: toml::Table = toml::from_str(&content).map_err(|e| {
+      syn::Error::new(
+        Span::call_site(),
+        format!("Failed to parse TOML file {}: {}", path.display(), e),
+      )
+    })?;
+
+    let mut entries = parallel_arrays(&mut table, &path)?;
+    let mut version = None;
+    // 省略 id 的标签按文件顺序依次取目前为止最大 id 之后的下一个值
+    let mut next_id = entries
+      .iter()
+      .map(|entry| entry.id.saturating_add(1))
+      .max()
+      .unwrap_or(0);
+    let mut auto = Vec::new();
+    for (key, value) in table {
+      if VERSION_KEYS.contains(&key.as_str()) {
+        if version.is_some() {
+          return Err(syn::Error::new(
+            Span::call_site(),
+            format!(
+              "{} declares both `version` and `schema_version`",
+              path.display()
+            ),
+          ));
+        }
+        let v = value.as_integer().and_then(|v| u32::try_from(v).ok());
+        version = Some(v.ok_or_else(|| {
+          syn::Error::new(
+            Span::call_site(),
+            format!(
+              "`{}` in {} must be a non-negative integer",
+              key,
+              path.display()
+            ),
+          )
+        })?);
+        continue;
+      }
+      let (entry, is_auto) = Entry::parse(key, value, &path, next_id)?;
+      next_id = next_id.max(entry.id.saturating_add(1));
+      if is_auto {
+        auto.push(entries.len());
+      }
+      entries.push(entry);
+    }
+
+    // 自动分配的 id 只会与之后显式写出的 id 冲突
+    for &i in &auto {
+      let entry = &entries[i];
+      if let Some(other) = entries
+        .iter()
+        .enumerate()
+        .find(|(j, other)| *j != i && other.id == entry.id)
+        .map(|(_, other)| other)
+      {
+        return Err(syn::Error::new(
+          Span::call_site(),
+          format!(
+            "Label `{}` in {} was assigned id {} automatically, which collides with `{}`",
+            entry.name,
+            path.display(),
+            entry.id,
+            other.name
+          ),
+        ));
+      }
+    }
+    entries.sort_by_key(|entry| entry.id);
+
+    Ok(Source {
+      files: vec![(path.clone(), content)],
+      path,
+      entries,
+      version,
+    })
+  }
+
+  /// 按路径读取，路径为目录时同 `read_dir`，否则同 `read`
+  pub(crate) fn read_any(path: &str) -> Result<Self, syn::Error> {
+    if resolve_path(path).is_dir() {
+      Source::read_dir(path)
+    } else {
+      Source::read(path)
+    }
+  }
+
+  /// 读取一个目录，其中每个文件是一个标签：去掉扩展名的文件名为标签名，文件内容为 id。
+  /// 文件中的空行与 `#` 注释行被忽略，其余须恰好一行整数。
+  ///
+  /// 以 `.` 开头的隐藏文件（如 `.gitkeep`）与子目录被忽略，文件按名称排序以保证结果确定。
+  pub(crate) fn read_dir(path: &str) -> Result<Self, syn::Error> {
+    Self::read_dir_path(resolve_path(path))
+  }
+
+  /// 同 `read_dir`，`path` 已解析
+  fn read_dir_path(path: PathBuf) -> Result<Self, syn::Error> {
+    let error = |message: String| syn::Error::new(Span::call_site(), message);
+
+    let mut paths = fs::read_dir(&path)
+      .map_err(|e| {
+        error(format!(
+          "Failed to read directory {}: {}",
+          path.display(),
+          e
+        ))
+      })?
+      .map(|entry| entry.map(|entry| entry.path()))
+      .collect::<Result<Vec<_>, _>>()
+      .map_err(|e| {
+        error(format!(
+          "Failed to read directory {}: {}",
+          path.display(),
+          e
+        ))
+      })?;
+    paths.retain(|file| {
+      file.is_file()
+        && !file
+          .file_name()
+          .is_some_and(|name| name.to_string_lossy().starts_with('.'))
+    });
+    paths.sort();
+
+    let mut files = Vec::with_capacity(paths.len());
+    let mut entries = Vec::with_capacity(paths.len());
+    for file in paths {
+      let name = file
+        .file_stem()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| {
+          error(format!(
+            "File name of {} is not valid UTF-8",
+            file.display()
+          ))
+        })?
+        .to_string();
+      let content = fs::read_to_string(&file)
+        .map_err(|e| error(format!("Failed to read file {}: {}", file.display(), e)))?;
+      let lines: Vec<&str> = content_lines(&content).collect();
+      let id = match lines.as_slice() {
+        [line] => line.parse().ok(),
+        _ => None,
+      }
+      .ok_or_else(|| {
+        error(format!(
+          "Label `{}` in {} must have an integer id",
+          name,
+          file.display()
+        ))
+      })?;
+      entries.push(Entry {
+        name,
+        id,
+        deprecated: None,
+        display: None,
+        groups: Vec::new(),
+        sort_order: None,
+        aliases: Vec::new(),
+        path: file.clone(),
+      });
+      files.push((file, content));
+    }
+    entries.sort_by_key(|entry| entry.id);
+
+    Ok(Source {
+      path,
+      files,
+      entries,
+      version: None,
+    })
+  }
+}
+
+impl Entry {
+  /// 解析一个条目；内联表省略 `id` 时取 `next_id`，并在返回值中标明 id 为自动分配
+  fn parse(
+    name: String,
+    value: toml::Value,
+    path: &Path,
+    next_id: i64,
+  ) -> Result<(Self, bool), syn::Error> {
+    let error = |message: &str| {
+      syn::Error::new(
+        Span::call_site(),
+        format!("Label `{}` in {} {}", name, path.display(), message),
+      )
+    };
+    if let Some(id) = integer_id(&value) {
+      return Ok((
+        Entry {
+          name,
+          id,
+          deprecated: None,
+          display: None,
+          groups: Vec::new(),
+          sort_order: None,
+          aliases: Vec::new(),
+          path: path.to_path_buf(),
+        },
+        false,
+      ));
+    }
+    match value {
+      toml::Value::Table(table) => {
+        let mut id = None;
+        let mut deprecated = None;
+        let mut display = None;
+        let mut groups = Vec::new();
+        let mut sort_order = None;
+        let mut aliases = Vec::new();
+        for (key, value) in table {
+          match key.as_str() {
+            "id" => id = Some(integer_id(&value).ok_or_else(|| error("must have an integer id"))?),
+            "deprecated" => match value {
+              toml::Value::String(note) => deprecated = Some(note),
+              _ => return Err(error("must have a string `deprecated` note")),
+            },
+            "display" => match value {
+              toml::Value::String(name) => display = Some(name),
+              _ => return Err(error("must have a string `display` name")),
+            },
+            "group" => {
+              let values = match value {
+                toml::Value::Array(values) => values,
+                value => vec![value],
+              };
+              for value in values {
+                match value {
+                  toml::Value::String(group) => groups.push(group),
+                  _ => return Err(error("must have a string or string array `group`")),
+                }
+              }
+            }
+            "alias" => {
+              let values = match value {
+                toml::Value::Array(values) => values,
+                value => vec![value],
+              };
+              for value in values {
+                match value {
+                  toml::Value::String(alias) => aliases.push(alias),
+                  _ => return Err(error("must have a string or string array `alias`")),
+                }
+              }
+            }
+            "sort_order" => match value {
+              toml::Value::Integer(order) => sort_order = Some(order),
+              _ => return Err(error("must have an integer `sort_order`")),
+            },
+            other => return Err(error(&format!("has unknown field `{}`", other))),
+          }
+        }
+        Ok((
+          Entry {
+            id: id.unwrap_or(next_id),
+            deprecated,
+            display,
+            groups,
+            sort_order,
+            aliases,
+            name,
+            path: path.to_path_buf(),
+          },
+          id.is_none(),
+        ))
+      }
+      _ => Err(error("must have an integer id")),
+    }
+  }
+}
+
+/// 读取 `names = [...]` 与 `ids = [...]` 两个平行数组形式的标签，并从表中移除这两个键。
+///
+/// 某些框架导出的元数据采用这种形式，两个数组按位置一一对应，长度必须相同。
+fn parallel_arrays(table: &mut toml::Table, path: &Path) -> Result<Vec<Entry>, syn::Error> {
+  let is_array = |key: &str| table.get(key).is_some_and(toml::Value::is_array);
+  if !is_array("names") && !is_array("ids") {
+    return Ok(Vec::new());
+  }
+  let error = |message: String| {
+    syn::Error::new(
+      Span::call_site(),
+      format!("{} in {}", message, path.display()),
+    )
+  };
+
+  let (Some(toml::Value::Array(names)), Some(toml::Value::Array(ids))) =
+    (table.remove("names"), table.remove("ids"))
+  else {
+    return Err(error(
+      "`names` and `ids` arrays must be given together".to_string(),
+    ));
+  };
+  if names.len() != ids.len() {
+    return Err(error(format!(
+      "`names` has {} elements but `ids` has {}",
+      names.len(),
+      ids.len()
+    )));
+  }
+
+  names
+    .into_iter()
+    .zip(ids)
+    .enumerate()
+    .map(|(i, (name, id))| match (name, integer_id(&id)) {
+      (toml::Value::String(name), Some(id)) => Ok(Entry {
+        name,
+        id,
+        deprecated: None,
+        display: None,
+        groups: Vec::new(),
+        sort_order: None,
+        aliases: Vec::new(),
+        path: path.to_path_buf(),
+      }),
+      _ => Err(error(format!(
+        "Element {} of `names` / `ids` must be a string / an integer",
+        i
+      ))),
+    })
+    .collect()
+}
+
+/// 非 TOML 格式中有内容的行：去除首尾空白，跳过空行与以 `#` 开头的注释行，
+/// 与 TOML 的注释写法保持一致。所有非 TOML 格式都应经由此函数读取内容
+fn content_lines(content: &str) -> impl Iterator<Item = &str> {
+  content
+    .lines()
+    .map(str::trim)
+    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// 整数 id；有些生成工具会给所有值加引号，因此也接受 `"0"` 这样的数字字符串
+fn integer_id(value: &toml::Value) -> Option<i64> {
+  match value {
+    toml::Value::Integer(id) => Some(*id),
+    toml::Value::String(id) => id.parse().ok(),
+    _ => None,
+  }
+}
+
+/// 按顺序合并多个标签文件，返回按 id 升序排列的条目。
+///
+/// 名称与 id 完全相同的条目视为同一标签，只保留一份，弃用说明等附加信息以后读到的为准；
+/// 同名但 id 不同、或不同名但 id 相同时报错。
+pub(crate) fn merge(sources: &[Source]) -> Result<Vec<Entry>, syn::Error> {
+  let mut by_name: HashMap<&str, (i64, &Path)> = HashMap::new();
+  let mut by_id: HashMap<i64, (&str, &Path)> = HashMap::new();
+  let mut merged: Vec<Entry> = Vec::new();
+
+  for source in sources {
+    for entry in &source.entries {
+      let (name, id) = (&entry.name, &entry.id);
+      if let Some((other_id, other_path)) = by_name.get(name.as_str()) {
+        if other_id == id {
+          if let Some(merged) = merged.iter_mut().find(|merged| &merged.name == name) {
+            *merged = entry.clone();
+          }
+          continue;
+        }
+        return Err(syn::Error::new(
+          Span::call_site(),
+          format!(
+            "Label `{}` has id {} in {} but id {} in {}",
+            name,
+            other_id,
+            other_path.display(),
+            id,
+            source.path.display()
+          ),
+        ));
+      }
+      if let Some((other_name, other_path)) = by_id.get(id) {
+        return Err(syn::Error::new(
+          Span::call_site(),
+          format!(
+            "Labels `{}` ({}) and `{}` ({}) share id {}",
+            other_name,
+            other_path.display(),
+            name,
+            source.path.display(),
+            id
+          ),
+        ));
+      }
+      by_name.insert(name, (*id, &source.path));
+      by_id.insert(*id, (name, &source.path));
+      merged.push(entry.clone());
+    }
+  }
+
+  merged.sort_by_key(|entry| entry.id);
+  Ok(merged)
+}
+
+/// 把参数 `arg` 给出的 `path` 解析为 `OUT_DIR` 下的路径，
+/// `path` 须为不含 `..` 的相对路径，且 crate 须有构建脚本以设置 `OUT_DIR`
+pub(crate) fn out_dir_path(arg: &str, path: &str) -> Result<PathBuf, syn::Error> {
+  let error = |message: String| syn::Error::new(Span::call_site(), message);
+
+  let relative = Path::new(path);
+  if !relative
+    .components()
+    .all(|component| matches!(component, Component::Normal(_)))
+  {
+    return Err(error(format!(
+      "`{}` path `{}` must be relative and must not contain `..`",
+      arg, path
+    )));
+  }
+  let out_dir = std::env::var_os("OUT_DIR").ok_or_else(|| {
+    error(format!(
+      "`{}` requires OUT_DIR, add a build.rs to the crate",
+      arg
+    ))
+  })?;
+  Ok(PathBuf::from(out_dir).join(relative))
+}
+
+/// 相对路径按 `CARGO_MANIFEST_DIR` 解析，未设置时按当前目录解析
+fn resolve_path(path: &str) -> PathBuf {
+  let path = PathBuf::from(path);
+  let path = match std::env::var_os("CARGO_MANIFEST_DIR") {
+    Some(dir) if path.is_relative() => PathBuf::from(dir).join(path),
+    _ => path,
+  };
+  fs::canonicalize(&path).unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn source(path: &str, content: &str) -> Source {
+    Source::parse(PathBuf::from(path), content.to_string()).unwrap()
+  }
+
+  /// 合并结果中的 `(名称, id)`，出错时返回错误信息
+  fn merged(files: &[(&str, &str)]) -> Result<Vec<(String, i64)>, String> {
+    let sources: Vec<Source> = files
+      .iter()
+      .map(|(path, content)| source(path, content))
+      .collect();
+    merge(&sources)
+      .map(|entries| {
+        entries
+          .into_iter()
+          .map(|entry| (entry.name, entry.id))
+          .collect()
+      })
+      .map_err(|e| e.to_string())
+  }
+
+  /// 单个文件解析出的 `(名称, id)`，出错时返回错误信息
+  fn parsed(content: &str) -> Result<Vec<(String, i64)>, String> {
+    Source::parse(PathBuf::from("labels.toml"), content.to_string())
+      .map(|source| {
+        source
+          .entries
+          .into_iter()
+          .map(|entry| (entry.name, entry.id))
+          .collect()
+      })
+      .map_err(|e| e.to_string())
+  }
+
+  #[test]
+  fn merge_identical_entries_is_a_no_op() {
+    let merged = merged(&[
+      ("base.toml", "cat = 0\ndog = 1"),
+      ("main.toml", "dog = 1\nbird = 2"),
+    ]);
+    assert_eq!(
+      merged.unwrap(),
+      [
+        ("cat".to_string(), 0),
+        ("dog".to_string(), 1),
+        ("bird".to_string(), 2)
+      ]
+    );
+  }
+
+  #[test]
+  fn merge_keeps_later_metadata() {
+    let sources = [
+      source("base.toml", "cat = 0"),
+      source("main.toml", "cat = { id = 0, display = \"Cat\" }"),
+    ];
+    let entries = merge(&sources).unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].display.as_deref(), Some("Cat"));
+  }
+
+  #[test]
+  fn merged_entries_remember_their_file() {
+    let sources = [
+      source("base.toml", "cat = { id = 0, alias = \"kitty\" }"),
+      source("main.toml", "names = [\"dog\"]\nids = [1]"),
+    ];
+    let paths: Vec<_> = merge(&sources)
+      .unwrap()
+      .into_iter()
+      .map(|entry| (entry.name, entry.path))
+      .collect();
+    assert_eq!(
+      paths,
+      [
+        ("cat".to_string(), PathBuf::from("base.toml")),
+        ("dog".to_string(), PathBuf::from("main.toml"))
+      ]
+    );
+  }
+
+  #[test]
+  fn merge_rejects_same_name_with_different_ids() {
+    let error = merged(&[("base.toml", "cat = 0"), ("main.toml", "cat = 3")]).unwrap_err();
+    assert_eq!(
+      error,
+      "Label `cat` has id 0 in base.toml but id 3 in main.toml"
+    );
+  }
+
+  #[test]
+  fn merge_rejects_different_names_with_the_same_id() {
+    let error = merged(&[("base.toml", "cat = 0"), ("main.toml", "kitty = 0")]).unwrap_err();
+    assert!(
+      error.starts_with("Labels `cat` (base.toml) and `kitty` (main.toml) share id 0"),
+      "{}",
+      error
+    );
+  }
+
+  #[test]
+  fn parallel_arrays_are_zipped() {
+    let entries = parsed("names = [\"cat\", \"dog\"]\nids = [3, 1]\nbird = 7").unwrap();
+    assert_eq!(
+      entries,
+      [
+        ("dog".to_string(), 1),
+        ("cat".to_string(), 3),
+        ("bird".to_string(), 7)
+      ]
+    );
+  }
+
+  #[test]
+  fn parallel_arrays_must_have_the_same_length() {
+    let error = parsed("names = [\"cat\", \"dog\"]\nids = [0]").unwrap_err();
+    assert_eq!(
+      error,
+      "`names` has 2 elements but `ids` has 1 in labels.toml"
+    );
+  }
+
+  #[test]
+  fn parallel_arrays_must_be_given_together() {
+    let error = parsed("names = [\"cat\"]").unwrap_err();
+    assert_eq!(
+      error,
+      "`names` and `ids` arrays must be given together in labels.toml"
+    );
+  }
+
+  #[test]
+  fn parallel_arrays_reject_wrong_element_types() {
+    let error = parsed("names = [\"cat\", 1]\nids = [0, 1]").unwrap_err();
+    assert_eq!(
+      error,
+      "Element 1 of `names` / `ids` must be a string / an integer in labels.toml"
+    );
+  }
+
+  #[test]
+  fn auto_ids_follow_the_largest_id_so_far() {
+    let entries = parsed("cat = 0\ndog = {}\nbird = 5\nfish = { group = \"water\" }").unwrap();
+    let ids: Vec<i64> = entries.iter().map(|(_, id)| *id).collect();
+    assert_eq!(ids, [0, 1, 5, 6]);
+  }
+
+  #[test]
+  fn auto_ids_must_not_collide_with_later_explicit_ids() {
+    let error = parsed("cat = 0\ndog = {}\nbird = 1").unwrap_err();
+    assert_eq!(
+      error,
+      "Label `dog` in labels.toml was assigned id 1 automatically, which collides with `bird`"
+    );
+  }
+
+  #[test]
+  fn numeric_strings_are_ids() {
+    let entries = parsed("cat = \"0\"\ndog = { id = \"-1\" }").unwrap();
+    assert_eq!(entries, [("dog".to_string(), -1), ("cat".to_string(), 0)]);
+  }
+
+  #[test]
+  fn non_numeric_strings_are_rejected() {
+    let error = parsed("cat = \"zero\"").unwrap_err();
+    assert_eq!(error, "Label `cat` in labels.toml must have an integer id");
+    let error = parsed("cat = { id = \"1.5\" }").unwrap_err();
+    assert_eq!(error, "Label `cat` in labels.toml must have an integer id");
+  }
+
+  #[test]
+  fn content_lines_skip_comments_and_blank_lines() {
+    let lines: Vec<&str> = content_lines("# header\n\n  3 \n  # note\n\t\n7\n").collect();
+    assert_eq!(lines, ["3", "7"]);
+  }
+}
@@ -0,0 +1,41 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/aliases.rs - 标签别名测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/aliases.toml", trait = "none")]
+enum Label {}
+
+#[toml_label(
+  file = "tests/labels/aliases.toml",
+  trait = "none",
+  lenient_parse = true
+)]
+enum Lenient {}
+
+#[test]
+fn aliases_parse_to_their_label() {
+  assert_eq!(Label::from_label_str("kitty"), Some(Label::Cat));
+  assert_eq!(Label::from_label_str("puss"), Some(Label::Cat));
+  assert_eq!(Label::from_label_str("hound"), Some(Label::Dog));
+  assert_eq!(Label::parse("hound"), Some(Label::Dog));
+  assert_eq!(Lenient::from_label_str(" Kitty "), Some(Lenient::Cat));
+}
+
+#[test]
+fn aliases_do_not_change_the_canonical_name() {
+  assert_eq!(
+    Label::from_label_str("kitty").unwrap().to_label_str(),
+    "cat"
+  );
+  assert_eq!(Label::label_id_of("kitty"), None);
+  assert_eq!(Label::id_of("hound"), None);
+  assert_eq!(Label::LABEL_NUM, 3);
+}
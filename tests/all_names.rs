@@ -0,0 +1,28 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/all_names.rs - `all_names` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/names_order.toml", trait = "none")]
+enum Label {}
+
+#[toml_label(file = "tests/labels/sparse.toml", trait = "none")]
+enum Sparse {}
+
+#[test]
+fn all_names_are_in_id_order_not_alphabetical() {
+  assert_eq!(Label::all_names(), ["zebra", "apple", "mango"]);
+}
+
+#[test]
+fn all_names_match_to_label_str() {
+  let names: Vec<String> = Sparse::iter().map(|label| label.to_label_str()).collect();
+  assert_eq!(Sparse::all_names(), names.as_slice());
+}
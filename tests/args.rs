@@ -0,0 +1,45 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/args.rs - 参数书写格式测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+// 各种空白与结尾逗号的写法都应得到同样的枚举；`rustfmt::skip` 防止格式化改写这些写法
+
+use shanan_macro::{toml_label, toml_label_enum};
+
+#[rustfmt::skip]
+#[toml_label(file="tests/labels/labels.toml",trait="none")]
+enum Compact {}
+
+#[rustfmt::skip]
+#[toml_label(file = "tests/labels/labels.toml", trait = "none",)]
+enum TrailingComma {}
+
+#[rustfmt::skip]
+#[toml_label(   file   =   "tests/labels/labels.toml"   ,   trait="none"  ,  )]
+enum Spaced {}
+
+#[rustfmt::skip]
+#[toml_label(
+    file =
+      "tests/labels/labels.toml",
+    trait = "none",
+)]
+enum Multiline {}
+
+#[rustfmt::skip]
+toml_label_enum!(name="Function",file="tests/labels/labels.toml",trait="none",);
+
+#[test]
+fn every_variation_parses() {
+  assert_eq!(Compact::BigBird.to_label_id(), 5);
+  assert_eq!(TrailingComma::BigBird.to_label_id(), 5);
+  assert_eq!(Spaced::BigBird.to_label_id(), 5);
+  assert_eq!(Multiline::BigBird.to_label_id(), 5);
+  assert_eq!(Function::BigBird.to_label_id(), 5);
+}
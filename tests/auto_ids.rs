@@ -0,0 +1,23 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/auto_ids.rs - 自动分配 id 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/auto.toml", trait = "none")]
+enum Label {}
+
+#[test]
+fn omitted_ids_follow_the_largest_so_far() {
+  assert_eq!(
+    [Label::Cat, Label::Dog, Label::Bird, Label::Fish].map(|label| label.to_label_id()),
+    [0, 1, 5, 6]
+  );
+  assert_eq!(Label::LABEL_NUM, 4);
+}
@@ -0,0 +1,28 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/closed.rs - 封闭模式判别值测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/sparse.toml", trait = "none", closed = true)]
+enum Label {}
+
+#[test]
+fn discriminant_equals_id() {
+  for label in [Label::Cat, Label::Dog, Label::Bird] {
+    assert_eq!(label as u32, label.to_label_id());
+  }
+  assert_eq!(Label::Bird as u32, 7);
+}
+
+#[test]
+#[should_panic(expected = "Unknown label id 4 for Label")]
+fn unknown_id_panics() {
+  Label::from_label_id(4);
+}
@@ -0,0 +1,22 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/comments.rs - 非 TOML 格式中的注释测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+/// 目录中的文件含有注释行、空行与首尾空白
+#[toml_label(dir = "tests/labels/comment_dir", trait = "none")]
+enum Label {}
+
+#[test]
+fn comment_lines_are_ignored_in_dir_files() {
+  assert_eq!(Label::LABEL_NUM, 2);
+  assert_eq!(Label::from_label_id(3), Label::Cat);
+  assert_eq!(Label::from_label_id(7), Label::Dog);
+}
@@ -0,0 +1,20 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/common/mod.rs - 集成测试共用的 `WithLabel` trait
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+// 各测试只用到其中一部分方法
+#![allow(dead_code)]
+
+/// 宏默认实现的 trait，与使用方 crate 中的定义一致
+pub trait WithLabel {
+  const LABEL_NUM: u32;
+  fn from_label_id(label_id: u32) -> Self;
+  fn to_label_str(&self) -> String;
+  fn to_label_id(&self) -> u32;
+}
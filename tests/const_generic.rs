@@ -0,0 +1,38 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/const_generic.rs - 以 `N` 作常量泛型参数的测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none")]
+enum Label {}
+
+/// 以类别数为常量泛型参数的模型包装
+struct Model<const N: usize> {
+  logits: [f32; N],
+}
+
+impl<const N: usize> Model<N> {
+  fn argmax(&self) -> usize {
+    (0..N)
+      .max_by(|&a, &b| self.logits[a].total_cmp(&self.logits[b]))
+      .unwrap()
+  }
+}
+
+#[test]
+fn model_sized_by_label_count() {
+  let mut model: Model<{ Label::N }> = Model {
+    logits: [0.0; Label::N],
+  };
+  assert_eq!(Label::N, 3);
+  assert_eq!(model.logits.len(), Label::LABEL_NUM as usize);
+  model.logits[2] = 1.0;
+  assert_eq!(Label::from_index(model.argmax()), Some(Label::BigBird));
+}
@@ -0,0 +1,72 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/const_maps.rs - ID_TO_NAME / NAME_TO_ID 常量表测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none")]
+enum Label {}
+
+/// 在 `ID_TO_NAME` 上二分查找，验证常量表可在 `const fn` 中使用
+const fn name_of(id: u32) -> Option<&'static str> {
+  let table = Label::ID_TO_NAME;
+  let (mut low, mut high) = (0, table.len());
+  while low < high {
+    let mid = low + (high - low) / 2;
+    if table[mid].0 == id {
+      return Some(table[mid].1);
+    } else if table[mid].0 < id {
+      low = mid + 1;
+    } else {
+      high = mid;
+    }
+  }
+  None
+}
+
+const DOG: Option<&str> = name_of(1);
+const MISSING: Option<&str> = name_of(2);
+
+#[test]
+fn id_to_name_is_sorted_by_id() {
+  assert_eq!(
+    Label::ID_TO_NAME,
+    &[(0, "cat"), (1, "dog"), (5, "big bird")]
+  );
+  assert!(Label::ID_TO_NAME.windows(2).all(|w| w[0].0 < w[1].0));
+}
+
+#[test]
+fn name_to_id_is_sorted_by_name() {
+  assert_eq!(
+    Label::NAME_TO_ID,
+    &[("big bird", 5), ("cat", 0), ("dog", 1)]
+  );
+  assert!(Label::NAME_TO_ID.windows(2).all(|w| w[0].0 < w[1].0));
+}
+
+#[test]
+fn const_lookup() {
+  assert_eq!(DOG, Some("dog"));
+  assert_eq!(MISSING, None);
+  assert_eq!(name_of(5), Some("big bird"));
+}
+
+#[test]
+fn name_to_id_binary_search() {
+  let search = |name: &str| {
+    Label::NAME_TO_ID
+      .binary_search_by(|(key, _)| key.cmp(&name))
+      .ok()
+      .map(|i| Label::NAME_TO_ID[i].1)
+  };
+  assert_eq!(search("cat"), Some(0));
+  assert_eq!(search("big bird"), Some(5));
+  assert_eq!(search("bird"), None);
+}
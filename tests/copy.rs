@@ -0,0 +1,22 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/copy.rs - `copy = false` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none", copy = false)]
+enum Label {}
+
+#[test]
+fn clone_without_copy() {
+  let label = Label::Dog;
+  let cloned = label.clone();
+  assert_eq!(label, cloned);
+  assert_eq!(cloned.to_label_id(), 1);
+}
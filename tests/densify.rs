@@ -0,0 +1,38 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/densify.rs - `densify` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/sparse.toml", trait = "none", densify = true)]
+enum Label {}
+
+#[test]
+fn label_ids_are_dense() {
+  assert_eq!(
+    [Label::Cat, Label::Dog, Label::Bird].map(|label| label.to_label_id()),
+    [0, 1, 2]
+  );
+  assert_eq!(Label::from_label_id(2), Label::Bird);
+  assert_eq!(Label::from_label_id(3), Label::Unknown(3));
+  assert_eq!(Label::MAX_LABEL_ID, 2);
+}
+
+#[test]
+fn original_ids_come_from_the_file() {
+  assert_eq!(
+    [Label::Cat, Label::Dog, Label::Bird].map(|label| label.original_id()),
+    [0, 3, 7]
+  );
+  assert_eq!(Label::Unknown(9).original_id(), 9);
+  assert_eq!(
+    Label::from_label_id(Label::Dog.to_label_id()).original_id(),
+    3
+  );
+}
@@ -0,0 +1,27 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/deprecated.rs - 弃用标签测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+#![allow(deprecated)]
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/deprecated.toml", trait = "none")]
+enum Label {}
+
+#[toml_label(file = "tests/labels/deprecated.toml", trait = "none", closed = true)]
+enum ClosedLabel {}
+
+#[test]
+fn deprecated_labels_still_resolve() {
+  assert_eq!(Label::from_label_id(1), Label::Old_dog);
+  assert_eq!(Label::Old_dog.to_label_str(), "old_dog");
+  assert_eq!(Label::from_label_str("old_dog"), Some(Label::Old_dog));
+  assert_eq!(ClosedLabel::from_label_id(2), ClosedLabel::Dog);
+}
@@ -0,0 +1,49 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/derive.rs - `#[derive(TomlLabel)]` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+mod common;
+
+use common::WithLabel;
+use shanan_macro::TomlLabel;
+
+#[derive(Debug, Clone, Copy, PartialEq, TomlLabel)]
+#[toml_label(file = "tests/labels/labels.toml")]
+enum Label {
+  Cat,
+  Dog,
+  BigBird,
+  Unknown(u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TomlLabel)]
+#[toml_label(file = "tests/labels/labels.toml", trait = "none", closed = true)]
+#[repr(u32)]
+enum Closed {
+  Cat = 0,
+  Dog = 1,
+  BigBird = 5,
+}
+
+#[test]
+fn derive_implements_the_trait() {
+  assert_eq!(<Label as WithLabel>::LABEL_NUM, 3);
+  assert_eq!(Label::from_label_id(5), Label::BigBird);
+  assert_eq!(Label::from_label_id(7), Label::Unknown(7));
+  assert_eq!(Label::Dog.to_label_str(), "dog");
+  assert_eq!(Label::Unknown(7).to_label_id(), 7);
+}
+
+#[test]
+fn derive_generates_associated_items() {
+  assert_eq!(Label::from_label_str("big bird"), Some(Label::BigBird));
+  assert_eq!(Label::ID_TO_NAME.len(), 3);
+  assert_eq!(Closed::BigBird as u32, Closed::BigBird.to_label_id());
+  assert_eq!(Closed::try_from_label_id(2).ok(), None);
+}
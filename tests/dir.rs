@@ -0,0 +1,31 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/dir.rs - 以目录为标签来源的测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+/// 目录中的 `.hidden.txt` 与子目录 `sub/` 不是标签
+#[toml_label(dir = "tests/labels/label_dir", trait = "none")]
+enum Label {}
+
+#[test]
+fn files_in_the_directory_are_labels() {
+  assert_eq!(Label::LABEL_NUM, 3);
+  assert_eq!(Label::from_label_id(0), Label::Cat);
+  assert_eq!(Label::from_label_id(5), Label::BigBird);
+  assert_eq!(Label::Dog.to_label_str(), "dog");
+  assert_eq!(Label::BigBird.to_label_str(), "big bird");
+}
+
+#[test]
+fn hidden_files_and_subdirectories_are_ignored() {
+  assert_eq!(Label::from_label_id(9), Label::Unknown(9));
+  assert_eq!(Label::from_label_str("fish"), None);
+  assert_eq!(Label::from_label_str(".hidden"), None);
+}
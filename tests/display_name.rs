@@ -0,0 +1,27 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/display_name.rs - `canonical_key` 与 `display_name` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/display.toml", trait = "none")]
+enum Label {}
+
+#[test]
+fn canonical_key_is_the_label_name() {
+  assert_eq!(Label::BigBird.canonical_key(), "big bird");
+  assert_eq!(Label::BigBird.to_label_str(), "big bird");
+  assert_eq!(Label::Unknown(3).canonical_key(), "unknown");
+}
+
+#[test]
+fn display_name_falls_back_to_the_label_name() {
+  assert_eq!(Label::BigBird.display_name(), "Big Bird");
+  assert_eq!(Label::Cat.display_name(), "cat");
+}
@@ -0,0 +1,32 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/display_order.rs - `iter_display_order` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/sort_order.toml", trait = "none")]
+enum Label {}
+
+#[test]
+fn display_order_follows_sort_order() {
+  // `mid` 未设置 `sort_order`，以 id 1 参与排序，与 `tied` 相同时按 id
+  assert_eq!(
+    Label::iter_display_order().collect::<Vec<_>>(),
+    [Label::High, Label::Mid, Label::Tied, Label::Low]
+  );
+}
+
+#[test]
+fn ids_are_unaffected() {
+  assert_eq!(
+    Label::iter().collect::<Vec<_>>(),
+    [Label::Low, Label::Mid, Label::High, Label::Tied]
+  );
+  assert_eq!(Label::High.to_label_id(), 2);
+}
@@ -0,0 +1,43 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// build.rs - 构建脚本，向 `OUT_DIR` 写出标签文件供 `out_dir_file` 读取
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+  println!("cargo::rerun-if-changed=build.rs");
+  let out_dir = env::var("OUT_DIR").unwrap();
+  let out_dir = Path::new(&out_dir);
+
+  // 模拟由模型元数据等生成的标签表
+  let generated = out_dir.join("generated");
+  fs::create_dir_all(&generated).unwrap();
+  fs::write(
+    generated.join("labels.toml"),
+    "red = 0\ngreen = 1\nblue = 7\n",
+  )
+  .unwrap();
+
+  // `verify_source` 测试会改写标签文件，因此改写的是每次构建复制到 `OUT_DIR` 的副本，
+  // 源码树中的 `tests/labels/drift*` 保持不变
+  println!("cargo::rerun-if-changed=../labels/drift.toml");
+  println!("cargo::rerun-if-changed=../labels/drift_dir");
+  fs::copy("../labels/drift.toml", out_dir.join("drift.toml")).unwrap();
+  let drift_dir = out_dir.join("drift_dir");
+  if drift_dir.exists() {
+    fs::remove_dir_all(&drift_dir).unwrap();
+  }
+  fs::create_dir_all(&drift_dir).unwrap();
+  for entry in fs::read_dir("../labels/drift_dir").unwrap() {
+    let path = entry.unwrap().path();
+    fs::copy(&path, drift_dir.join(path.file_name().unwrap())).unwrap();
+  }
+}
@@ -0,0 +1,29 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// src/lib.rs - 以下游 crate 的身份使用标签宏
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+//! 测试专用的下游 crate，`non_exhaustive` 等行为只在跨 crate 使用时可见。
+
+use shanan_macro::toml_label;
+
+/// 声明为 `non_exhaustive`，下游的 `match` 须带通配分支
+#[toml_label(file = "../labels/labels.toml", trait = "none", non_exhaustive = true)]
+pub enum Label {}
+
+/// 展开时把标签表导出到 `OUT_DIR/labels/labels.json`
+#[toml_label(
+  file = "../labels/labels.toml",
+  trait = "none",
+  emit_json = "labels/labels.json"
+)]
+pub enum Exported {}
+
+/// 读取 `build.rs` 写入 `OUT_DIR/generated/labels.toml` 的标签表
+#[toml_label(out_dir_file = "generated/labels.toml", trait = "none")]
+pub enum Generated {}
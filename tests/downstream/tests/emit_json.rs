@@ -0,0 +1,32 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/emit_json.rs - `emit_json` 导出内容测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use std::collections::BTreeMap;
+
+use shanan_macro_downstream::Exported;
+
+const JSON: &str = include_str!(concat!(env!("OUT_DIR"), "/labels/labels.json"));
+const TOML: &str = include_str!("../../labels/labels.toml");
+
+#[test]
+fn emitted_json_matches_the_label_file() {
+  let json: BTreeMap<String, i64> = serde_json::from_str(JSON).unwrap();
+  let toml: BTreeMap<String, i64> = toml::from_str(TOML).unwrap();
+  assert_eq!(json, toml);
+  assert_eq!(json.len(), Exported::LABEL_NUM as usize);
+}
+
+#[test]
+fn emitted_json_is_ordered_by_id() {
+  assert_eq!(
+    JSON,
+    "{\n  \"cat\": 0,\n  \"dog\": 1,\n  \"big bird\": 5\n}\n"
+  );
+}
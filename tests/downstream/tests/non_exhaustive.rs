@@ -0,0 +1,27 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/non_exhaustive.rs - `non_exhaustive` 枚举的下游匹配
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro_downstream::Label;
+
+fn describe(label: Label) -> &'static str {
+  match label {
+    Label::Cat => "cat",
+    Label::Dog => "dog",
+    Label::Unknown(_) => "unknown",
+    _ => "other",
+  }
+}
+
+#[test]
+fn wildcard_arm_covers_the_rest() {
+  assert_eq!(describe(Label::from_label_id(0)), "cat");
+  assert_eq!(describe(Label::from_label_id(5)), "other");
+  assert_eq!(describe(Label::from_label_id(9)), "unknown");
+}
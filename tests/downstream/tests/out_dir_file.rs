@@ -0,0 +1,31 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/out_dir_file.rs - `out_dir_file` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use std::collections::BTreeMap;
+
+use shanan_macro_downstream::Generated;
+
+const TOML: &str = include_str!(concat!(env!("OUT_DIR"), "/generated/labels.toml"));
+
+#[test]
+fn labels_come_from_the_build_script_output() {
+  let expected: BTreeMap<String, u32> = toml::from_str(TOML).unwrap();
+  let actual: BTreeMap<String, u32> = Generated::iter()
+    .map(|label| (label.to_label_str(), label.to_label_id()))
+    .collect();
+  assert_eq!(actual, expected);
+}
+
+#[test]
+fn generated_labels_are_usable() {
+  assert_eq!(Generated::Blue.to_label_id(), 7);
+  assert_eq!(Generated::from_label_str("green"), Some(Generated::Green));
+  assert_eq!(Generated::from_label_id(2), Generated::Unknown(2));
+}
@@ -0,0 +1,16 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/ui.rs - 下游 crate 编译失败的用例
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+/// 与主 crate 的 `tests/ui.rs` 相同，`TRYBUILD=overwrite` 可更新期望输出
+#[test]
+fn ui() {
+  let cases = trybuild::TestCases::new();
+  cases.compile_fail("tests/ui/*.rs");
+}
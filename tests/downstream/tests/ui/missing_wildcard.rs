@@ -0,0 +1,21 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/ui/missing_wildcard.rs - 下游匹配 `non_exhaustive` 枚举须带通配分支
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro_downstream::Label;
+
+fn main() {
+  let name = match Label::Cat {
+    Label::Cat => "cat",
+    Label::Dog => "dog",
+    Label::BigBird => "big bird",
+    Label::Unknown(_) => "unknown",
+  };
+  println!("{}", name);
+}
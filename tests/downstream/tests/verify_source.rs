@@ -0,0 +1,94 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/verify_source.rs - `verify_source` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+// 宏在测试函数内部展开：生成的 `#[test]` 不会被测试框架收集，只由本测试直接调用，
+// 避免它与下面修改标签文件的步骤并发运行。
+//
+// 被修改的是构建脚本复制到 `OUT_DIR` 的副本而不是源码树中的文件；每个测试开始时先把
+// 副本恢复为原始内容，即使上一次运行被中断、来不及恢复，也不会影响本次结果。
+
+use std::fs;
+use std::panic;
+use std::path::{Path, PathBuf};
+
+const DRIFT: &str = include_str!("../../labels/drift.toml");
+
+fn out_dir(path: &str) -> PathBuf {
+  Path::new(env!("OUT_DIR")).join(path)
+}
+
+/// 把 `OUT_DIR` 中的副本恢复为原始内容，测试结束时（包括失败时）同样恢复
+struct Restore(fn());
+
+impl Restore {
+  fn new(restore: fn()) -> Self {
+    restore();
+    Restore(restore)
+  }
+}
+
+impl Drop for Restore {
+  fn drop(&mut self) {
+    (self.0)();
+  }
+}
+
+fn restore_file() {
+  fs::write(out_dir("drift.toml"), DRIFT).unwrap();
+}
+
+fn restore_dir() {
+  let _ = fs::remove_file(out_dir("drift_dir/bird.txt"));
+}
+
+fn panic_message(result: std::thread::Result<()>) -> String {
+  let payload = result.expect_err("verification should fail after the label file drifted");
+  match payload.downcast::<String>() {
+    Ok(message) => *message,
+    Err(payload) => payload
+      .downcast_ref::<&str>()
+      .map(|message| message.to_string())
+      .unwrap_or_default(),
+  }
+}
+
+#[test]
+#[allow(unnameable_test_items)]
+fn file_drift_is_detected() {
+  #[shanan_macro::toml_label(out_dir_file = "drift.toml", trait = "none", verify_source = true)]
+  enum Drift {}
+
+  let _restore = Restore::new(restore_file);
+  verify_drift_source();
+
+  fs::write(out_dir("drift.toml"), "cat = 0\ndog = 1\nbird = 2\n").unwrap();
+  let message = panic_message(panic::catch_unwind(verify_drift_source));
+  assert!(
+    message.contains("has changed since Drift was generated"),
+    "{}",
+    message
+  );
+  assert_eq!(Drift::LABEL_NUM, 2);
+}
+
+#[test]
+#[allow(unnameable_test_items)]
+fn dir_drift_is_detected() {
+  #[shanan_macro::toml_label(out_dir_file = "drift_dir", trait = "none", verify_source = true)]
+  enum DriftDir {}
+
+  let _restore = Restore::new(restore_dir);
+  verify_drift_dir_source();
+
+  fs::write(out_dir("drift_dir/bird.txt"), "2\n").unwrap();
+  let message = panic_message(panic::catch_unwind(verify_drift_dir_source));
+  assert!(message.contains("now holds"), "{}", message);
+  assert_eq!(DriftDir::LABEL_NUM, 2);
+}
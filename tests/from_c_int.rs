@@ -0,0 +1,107 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/from_c_int.rs - `from_c_int` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none")]
+enum Label {}
+
+/// id 类型两端的值都是标签 id，哨兵值须向内查找
+#[toml_label(file = "tests/labels/u8_bounds.toml", trait = "none", id_type = "u8")]
+enum Narrow {}
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none", id_type = "i8")]
+enum Signed {}
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none", id_type = "i64")]
+enum Wide {}
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none", id_type = "u64")]
+enum Unsigned {}
+
+/// `u32` 顶端的 id 已被保留，哨兵值须继续往下找
+#[toml_label(
+  file = "tests/labels/labels.toml",
+  trait = "none",
+  reserved = [[4294967290, 4294967295]]
+)]
+enum Capped {}
+
+#[toml_label(
+  file = "tests/labels/labels.toml",
+  trait = "none",
+  id_type = "u8",
+  unknown_above = 10
+)]
+enum Collapsed {}
+
+#[toml_label(
+  file = "tests/labels/u8_bounds.toml",
+  trait = "none",
+  id_type = "u8",
+  reserved = [[2, 9], [250, 253]]
+)]
+enum Guarded {}
+
+#[test]
+fn in_range_values_are_ids() {
+  assert_eq!(Label::from_c_int(0), Label::Cat);
+  assert_eq!(Label::from_c_int(5), Label::BigBird);
+  assert_eq!(Label::from_c_int(i32::MAX), Label::Unknown(i32::MAX as u32));
+  assert_eq!(Narrow::from_c_int(0), Narrow::Zero);
+  assert_eq!(Narrow::from_c_int(255), Narrow::Top);
+}
+
+#[test]
+fn negative_values_do_not_become_labels() {
+  assert_eq!(Label::from_c_int(-1), Label::Unknown(u32::MAX));
+  assert_eq!(Label::from_c_int(i32::MIN), Label::Unknown(u32::MAX));
+  assert_eq!(Narrow::from_c_int(-1), Narrow::Unknown(2));
+  assert_eq!(Unsigned::from_c_int(-1), Unsigned::Unknown(u64::MAX));
+  assert_eq!(Capped::from_c_int(-1), Capped::Unknown(4294967289));
+}
+
+#[test]
+fn wide_sentinels_differ_from_in_range_values() {
+  // `i32` 的非负值到不了 `i32::MAX` 以上的 id，负数的结果因此能与之区分
+  assert_ne!(Label::from_c_int(-1), Label::from_c_int(2));
+  assert_ne!(Label::from_c_int(-1), Label::from_c_int(i32::MAX));
+  assert_ne!(Unsigned::from_c_int(-1), Unsigned::from_c_int(2));
+  assert_ne!(Capped::from_c_int(-1), Capped::from_c_int(2));
+}
+
+#[test]
+fn oversized_values_do_not_become_labels() {
+  assert_eq!(Narrow::from_c_int(256), Narrow::Unknown(253));
+  assert_eq!(Narrow::from_c_int(i32::MAX), Narrow::Unknown(253));
+}
+
+#[test]
+fn signed_ids_keep_negative_values() {
+  assert_eq!(Signed::from_c_int(-3), Signed::Unknown(-3));
+  assert_eq!(Signed::from_c_int(-128), Signed::Unknown(-128));
+  assert_eq!(Signed::from_c_int(1), Signed::Dog);
+  assert_eq!(Signed::from_c_int(-129), Signed::Unknown(-128));
+  assert_eq!(Signed::from_c_int(128), Signed::Unknown(127));
+  assert_eq!(Wide::from_c_int(-5), Wide::Unknown(-5));
+  assert_eq!(
+    Wide::from_c_int(i32::MIN),
+    Wide::Unknown(i64::from(i32::MIN))
+  );
+}
+
+#[test]
+fn sentinels_respect_unknown_above_and_reserved() {
+  assert_eq!(Collapsed::from_c_int(200), Collapsed::Unknown(10));
+  assert_eq!(Collapsed::from_c_int(300), Collapsed::Unknown(10));
+  assert_eq!(Guarded::from_c_int(-1), Guarded::Unknown(10));
+  assert_eq!(Guarded::from_c_int(256), Guarded::Unknown(249));
+  assert_eq!(Guarded::from_c_int(251), Guarded::Reserved(251));
+}
@@ -0,0 +1,45 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/from_json.rs - `from_json` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use serde_json::json;
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none", from_json = true)]
+enum Label {}
+
+#[toml_label(
+  file = "tests/labels/labels.toml",
+  trait = "none",
+  closed = true,
+  from_json = true
+)]
+enum ClosedLabel {}
+
+#[test]
+fn ids_and_names_are_accepted() {
+  assert_eq!(Label::from_json(&json!(1)), Some(Label::Dog));
+  assert_eq!(Label::from_json(&json!("big bird")), Some(Label::BigBird));
+  assert_eq!(Label::from_json(&json!(9)), Some(Label::Unknown(9)));
+}
+
+#[test]
+fn other_values_are_rejected() {
+  assert_eq!(Label::from_json(&json!(-1)), None);
+  assert_eq!(Label::from_json(&json!(1.5)), None);
+  assert_eq!(Label::from_json(&json!("horse")), None);
+  assert_eq!(Label::from_json(&json!([1])), None);
+  assert_eq!(Label::from_json(&json!(null)), None);
+}
+
+#[test]
+fn closed_mode_rejects_unknown_ids() {
+  assert_eq!(ClosedLabel::from_json(&json!(0)), Some(ClosedLabel::Cat));
+  assert_eq!(ClosedLabel::from_json(&json!(9)), None);
+}
@@ -0,0 +1,80 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/from_logits.rs - `from_logits` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/names_order.toml", trait = "none")]
+enum Label {}
+
+#[toml_label(file = "tests/labels/gap.toml", trait = "none", output_dim = "max_id")]
+enum Gap {}
+
+#[test]
+fn argmax_selects_the_label() {
+  assert_eq!(Label::from_logits(&[0.1, 2.0, -1.0]), Ok(Label::Apple));
+  assert_eq!(Label::from_logits(&[-3.0, -2.0, -1.0]), Ok(Label::Mango));
+  assert_eq!(
+    Label::from_logits(&[f32::NEG_INFINITY, f32::INFINITY, 0.0]),
+    Ok(Label::Apple)
+  );
+}
+
+#[test]
+fn ties_go_to_the_first_maximum() {
+  assert_eq!(Label::from_logits(&[0.5, 0.5, 0.1]), Ok(Label::Zebra));
+  assert_eq!(Label::from_logits(&[0.0; 3]), Ok(Label::Zebra));
+}
+
+#[test]
+fn length_must_match_output_dim() {
+  assert_eq!(
+    Label::from_logits(&[1.0, 2.0]),
+    Err(LabelLogitsError::Length {
+      expected: 3,
+      found: 2
+    })
+  );
+  assert_eq!(
+    Label::from_logits(&[]),
+    Err(LabelLogitsError::Length {
+      expected: 3,
+      found: 0
+    })
+  );
+  assert_eq!(
+    LabelLogitsError::Length {
+      expected: 3,
+      found: 2
+    }
+    .to_string(),
+    "expected 3 logits for Label but found 2"
+  );
+}
+
+#[test]
+fn nan_is_rejected() {
+  assert_eq!(
+    Label::from_logits(&[1.0, f32::NAN, 0.0]),
+    Err(LabelLogitsError::NotANumber(1))
+  );
+  assert_eq!(
+    Label::from_logits(&[f32::NAN; 3]),
+    Err(LabelLogitsError::NotANumber(0))
+  );
+}
+
+#[test]
+fn max_id_dimensions_without_a_label_are_errors() {
+  assert_eq!(Gap::from_logits(&[0.0, 0.0, 0.0, 0.5]), Ok(Gap::B));
+  assert_eq!(
+    Gap::from_logits(&[0.0, 1.0, 0.0, 0.5]),
+    Err(GapLogitsError::NoLabel(1))
+  );
+}
@@ -0,0 +1,35 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/groups.rs - 标签分组测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/groups.toml", trait = "none")]
+enum Label {}
+
+#[toml_label(file = "tests/labels/groups.toml", trait = "none", closed = true)]
+enum ClosedLabel {}
+
+#[test]
+fn group_constants_list_members_in_id_order() {
+  assert_eq!(Label::ANIMALS, &[Label::Cat, Label::Dog, Label::BigCat]);
+  assert_eq!(Label::PETS, &[Label::Dog]);
+  assert_eq!(Label::BIG_CATS, &[Label::BigCat]);
+}
+
+#[test]
+fn group_predicates() {
+  assert!(Label::Cat.is_animal());
+  assert!(Label::Dog.is_animal() && Label::Dog.is_pet());
+  assert!(!Label::Cat.is_pet());
+  assert!(!Label::Rock.is_animal());
+  assert!(!Label::Unknown(9).is_animal());
+  assert!(ClosedLabel::BigCat.is_big_cat());
+  assert!(!ClosedLabel::Rock.is_big_cat());
+}
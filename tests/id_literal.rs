@@ -0,0 +1,33 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/id_literal.rs - 带类型后缀的 id 字面量测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none", repr = "u8")]
+enum Label {}
+
+// 以下位置都要求具体的 `u8` 类型，未加后缀的字面量在此会推断失败或类型不符
+const FIRST_ID: u8 = Label::ID_TO_NAME[0].0;
+const DOG_ID: Option<u8> = Label::id_of("dog");
+static IDS: [u8; 3] = Label::INDEX_TO_ID;
+
+fn takes_u8(id: u8) -> u8 {
+  id
+}
+
+#[test]
+fn ids_have_the_repr_type() {
+  assert_eq!(FIRST_ID, 0u8);
+  assert_eq!(DOG_ID, Some(1u8));
+  assert_eq!(IDS, [0u8, 1, 5]);
+  assert_eq!(takes_u8(Label::BigBird.to_label_id()), 5);
+  assert_eq!(Label::from_label_id(takes_u8(1)), Label::Dog);
+  assert_eq!(Label::NAME_TO_ID[0], ("big bird", 5u8));
+}
@@ -0,0 +1,35 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/id_of.rs - `id_of` 常量查询测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none")]
+enum Label {}
+
+const CAT: Option<u32> = Label::id_of("cat");
+const BIG_BIRD: Option<u32> = Label::id_of("big bird");
+const PREFIX: Option<u32> = Label::id_of("ca");
+const LONGER: Option<u32> = Label::id_of("cats");
+
+/// 用作数组长度，确认结果确实在编译期求值
+const BIRD_SLOTS: [u8; 5] = [0; match Label::id_of("big bird") {
+  Some(id) => id as usize,
+  None => 0,
+}];
+
+#[test]
+fn id_of_works_in_const_context() {
+  assert_eq!(CAT, Some(0));
+  assert_eq!(BIG_BIRD, Some(5));
+  assert_eq!(PREFIX, None);
+  assert_eq!(LONGER, None);
+  assert_eq!(BIRD_SLOTS.len(), 5);
+  assert_eq!(Label::Cat.to_label_id(), 0);
+}
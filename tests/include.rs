@@ -0,0 +1,41 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/include.rs - `include` 合并测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(
+  file = "tests/labels/extended.toml",
+  include = "tests/labels/base.toml",
+  trait = "none"
+)]
+enum Label {}
+
+#[test]
+fn identical_entries_merge() {
+  assert_eq!(Label::LABEL_NUM, 3);
+  assert_eq!(Label::ID_TO_NAME, &[(0, "cat"), (1, "dog"), (2, "bird")]);
+}
+
+/// 只有 `include` 可以重复给出
+#[toml_label(
+  file = "tests/labels/labels.toml",
+  include = "tests/labels/base.toml",
+  include = "tests/labels/extended.toml",
+  trait = "none"
+)]
+enum Repeated {}
+
+#[test]
+fn include_can_be_repeated() {
+  assert_eq!(
+    Repeated::ID_TO_NAME,
+    &[(0, "cat"), (1, "dog"), (2, "bird"), (5, "big bird")]
+  );
+}
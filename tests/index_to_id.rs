@@ -0,0 +1,27 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/index_to_id.rs - `INDEX_TO_ID` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/sparse.toml", trait = "none")]
+enum Label {}
+
+const BIRD_ID: u32 = Label::INDEX_TO_ID[2];
+
+#[test]
+fn index_to_id_matches_dense_index() {
+  assert_eq!(Label::INDEX_TO_ID, [0, 3, 7]);
+  assert_eq!(BIRD_ID, 7);
+  for (index, &id) in Label::INDEX_TO_ID.iter().enumerate() {
+    let label = Label::from_index(index).unwrap();
+    assert_eq!(label.to_label_id(), id);
+    assert_eq!(label.index(), Some(index));
+  }
+}
@@ -0,0 +1,60 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/indexed.rs - `{枚举名}Indexed` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::{TomlLabel, toml_label};
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none")]
+enum Label {}
+
+#[test]
+fn indexed_by_label() {
+  let mut counts = LabelIndexed([0u32; 3]);
+  counts[Label::Dog] += 2;
+  counts[Label::BigBird] = 7;
+  assert_eq!(counts.0, [0, 2, 7]);
+  assert_eq!(counts[Label::Dog], 2);
+  assert_eq!(counts.get(&Label::Cat), Some(&0));
+  assert_eq!(counts.get(&Label::Unknown(4)), None);
+}
+
+#[test]
+#[should_panic(expected = "Label without a dense index used to index LabelIndexed")]
+fn indexing_with_unknown_panics() {
+  let counts = LabelIndexed([0u32; 3]);
+  let _ = counts[Label::Unknown(4)];
+}
+
+// 派生宏的枚举未实现 `Debug` 时同样可以使用 `Indexed`
+#[derive(Clone, Copy, PartialEq, TomlLabel)]
+#[toml_label(file = "tests/labels/labels.toml", trait = "none")]
+enum Plain {
+  Cat,
+  Dog,
+  BigBird,
+  Unknown(u32),
+}
+
+#[test]
+fn indexed_without_debug() {
+  let mut counts = PlainIndexed([0u32; 3]);
+  counts[Plain::Dog] += 2;
+  counts[Plain::BigBird] += 1;
+  assert_eq!(counts[Plain::BigBird], 1);
+  assert_eq!(counts.get(&Plain::Cat), Some(&0));
+  assert_eq!(counts.0, [0, 2, 1]);
+  assert_eq!(counts.get(&Plain::Unknown(4)), None);
+}
+
+#[test]
+#[should_panic(expected = "Label without a dense index used to index PlainIndexed")]
+fn indexing_without_debug_panics() {
+  let mut counts = PlainIndexed([0u32; 3]);
+  counts[Plain::Unknown(4)] += 1;
+}
@@ -0,0 +1,38 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/item_names.rs - 自定义方法名测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+/// 已有的领域 trait，方法名与 `WithLabel` 不同，也没有标签个数常量
+trait ClassLabel {
+  fn from_class(id: u32) -> Self;
+  fn class_name(&self) -> String;
+  fn class_id(&self) -> u32;
+}
+
+#[toml_label(
+  file = "tests/labels/labels.toml",
+  trait = "ClassLabel",
+  from_id_method = "from_class",
+  name_method = "class_name",
+  id_method = "class_id",
+  num_const = "none"
+)]
+enum Label {}
+
+fn describe<T: ClassLabel>(label: &T) -> String {
+  format!("{}#{}", label.class_name(), label.class_id())
+}
+
+#[test]
+fn custom_named_trait_is_implemented() {
+  assert_eq!(describe(&Label::from_class(5)), "big bird#5");
+  assert_eq!(describe(&Label::from_class(9)), "unknown9#9");
+}
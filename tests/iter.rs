@@ -0,0 +1,46 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/iter.rs - `iter` 与 `iter_where` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/groups.toml", trait = "none")]
+enum Label {}
+
+#[toml_label(file = "tests/labels/groups.toml", trait = "none", copy = false)]
+enum Owned {}
+
+#[test]
+fn iter_yields_every_label_in_id_order() {
+  assert_eq!(
+    Label::iter().collect::<Vec<_>>(),
+    [Label::Cat, Label::Dog, Label::Rock, Label::BigCat]
+  );
+  assert_eq!(Label::iter().count(), Label::N);
+  assert_eq!(Label::ALL.len(), Label::N);
+}
+
+#[test]
+fn iter_where_filters() {
+  assert_eq!(
+    Label::iter_where(Label::is_pet).collect::<Vec<_>>(),
+    [Label::Dog]
+  );
+  assert_eq!(
+    Label::iter_where(|label| label.to_label_id() >= 2).collect::<Vec<_>>(),
+    [Label::Rock, Label::BigCat]
+  );
+  assert_eq!(Label::iter_where(|_| false).count(), 0);
+}
+
+#[test]
+fn iteration_does_not_require_copy() {
+  assert_eq!(Owned::iter_where(Owned::is_animal).count(), 3);
+  assert_eq!(Owned::iter().last(), Some(Owned::BigCat));
+}
@@ -0,0 +1,17 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/labels_match.rs - `assert_labels_match!` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+// 名称集合相同即通过，与 id、顺序及来源格式无关
+shanan_macro::assert_labels_match!("tests/labels/labels.toml", "tests/labels/reordered.toml");
+shanan_macro::assert_labels_match!("tests/labels/arrays.toml", "tests/labels/labels.toml",);
+shanan_macro::assert_labels_match!("tests/labels/label_dir", "tests/labels/labels.toml");
+
+#[test]
+fn matching_files_compile() {}
@@ -0,0 +1,37 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/lenient_parse.rs - `lenient_parse` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(
+  file = "tests/labels/labels.toml",
+  trait = "none",
+  lenient_parse = true
+)]
+enum Lenient {}
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none")]
+enum Strict {}
+
+#[test]
+fn lenient_parse_ignores_case_and_surrounding_whitespace() {
+  assert_eq!(Lenient::from_label_str(" CAT "), Some(Lenient::Cat));
+  assert_eq!(
+    Lenient::from_label_str("Big Bird\n"),
+    Some(Lenient::BigBird)
+  );
+  assert_eq!(Lenient::from_label_str("bigbird"), None);
+}
+
+#[test]
+fn strict_parse_is_exact() {
+  assert_eq!(Strict::from_label_str(" CAT "), None);
+  assert_eq!(Strict::from_label_str("cat"), Some(Strict::Cat));
+}
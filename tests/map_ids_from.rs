@@ -0,0 +1,90 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/map_ids_from.rs - `map_ids_from` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+/// 外部 id 0 → big bird，1 → dog，2 → cat，3 → 不存在的标签 id 7
+const REMAP: [u32; 4] = [5, 1, 0, 7];
+
+mod table {
+  pub const REMAP: &[u32] = &[1, 1, 7];
+}
+
+#[toml_label(
+  file = "tests/labels/labels.toml",
+  trait = "none",
+  map_ids_from = "REMAP"
+)]
+enum Label {}
+
+#[toml_label(
+  file = "tests/labels/labels.toml",
+  trait = "none",
+  map_ids_from = "REMAP",
+  closed = true,
+  from_json = true
+)]
+enum ClosedLabel {}
+
+#[toml_label(
+  file = "tests/labels/labels.toml",
+  trait = "none",
+  map_ids_from = "table::REMAP",
+  module = "nested"
+)]
+enum Nested {}
+
+#[test]
+fn external_ids_are_remapped() {
+  assert_eq!(Label::from_label_id(0), Label::BigBird);
+  assert_eq!(Label::from_label_id(1), Label::Dog);
+  assert_eq!(Label::from_label_id(2), Label::Cat);
+  assert_eq!(Label::from_label_id(3), Label::Unknown(7));
+  assert_eq!(Label::from_label_id(4), Label::Unknown(4));
+  assert_eq!(Label::parse("0"), Some(Label::BigBird));
+}
+
+#[test]
+fn table_paths_resolve_outside_the_module() {
+  assert_eq!(nested::Nested::from_label_id(0), nested::Nested::Dog);
+  assert_eq!(nested::Nested::from_label_id(2), nested::Nested::Unknown(7));
+}
+
+#[test]
+fn closed_parse_remaps_before_checking() {
+  assert_eq!(ClosedLabel::from_label_id(0), ClosedLabel::BigBird);
+  assert_eq!(ClosedLabel::parse("0"), Some(ClosedLabel::BigBird));
+  assert_eq!(ClosedLabel::parse("2"), Some(ClosedLabel::Cat));
+  assert_eq!(ClosedLabel::parse("3"), None);
+  assert_eq!(ClosedLabel::parse("4"), None);
+  assert_eq!(ClosedLabel::parse("cat"), Some(ClosedLabel::Cat));
+}
+
+#[test]
+fn closed_from_json_remaps_before_checking() {
+  use serde_json::json;
+
+  assert_eq!(ClosedLabel::from_json(&json!(1)), Some(ClosedLabel::Dog));
+  assert_eq!(ClosedLabel::from_json(&json!(2)), Some(ClosedLabel::Cat));
+  assert_eq!(ClosedLabel::from_json(&json!(4)), None);
+}
+
+#[test]
+fn try_from_label_id_takes_label_ids() {
+  assert_eq!(ClosedLabel::try_from_label_id(0), Ok(ClosedLabel::Cat));
+  assert_eq!(ClosedLabel::try_from_label_id(5), Ok(ClosedLabel::BigBird));
+  assert!(ClosedLabel::try_from_label_id(2).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Label id 4 is not covered by REMAP")]
+fn closed_from_label_id_panics_outside_the_table() {
+  let _ = ClosedLabel::from_label_id(4);
+}
@@ -0,0 +1,36 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/name_id.rs - `label_id_of` / `label_name_of` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none")]
+enum Label {}
+
+// 同一模块中的第二个枚举，关联函数不会与 `Label` 的冲突
+#[toml_label(file = "tests/labels/sparse.toml", trait = "none")]
+enum Sparse {}
+
+#[test]
+fn name_to_id() {
+  assert_eq!(Label::label_id_of("cat"), Some(0));
+  assert_eq!(Label::label_id_of("big bird"), Some(5));
+  assert_eq!(Label::label_id_of("bird"), None);
+  assert_eq!(Label::label_id_of(""), None);
+  assert_eq!(Sparse::label_id_of("bird"), Some(7));
+}
+
+#[test]
+fn id_to_name() {
+  assert_eq!(Label::label_name_of(1), Some("dog"));
+  assert_eq!(Label::label_name_of(5), Some("big bird"));
+  assert_eq!(Label::label_name_of(2), None);
+  assert_eq!(Label::label_name_of(u32::MAX), None);
+  assert_eq!(Sparse::label_name_of(3), Some("dog"));
+}
@@ -0,0 +1,51 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/name_set.rs - `NAME_SET` 与 `is_known_name` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none", name_set = true)]
+enum Label {}
+
+#[toml_label(file = "tests/labels/aliases.toml", trait = "none", name_set = true)]
+enum Aliased {}
+
+#[toml_label(
+  file = "tests/labels/labels.toml",
+  trait = "none",
+  lenient_parse = true,
+  name_set = true
+)]
+enum Lenient {}
+
+#[test]
+fn exact_names_are_known() {
+  assert_eq!(Label::NAME_SET.len(), 3);
+  assert!(Label::is_known_name("cat") && Label::is_known_name("big bird"));
+  assert!(!Label::is_known_name("Cat") && !Label::is_known_name(""));
+}
+
+#[test]
+fn aliases_are_known() {
+  assert_eq!(Aliased::NAME_SET.len(), 6);
+  assert!(Aliased::is_known_name("kitty") && Aliased::is_known_name("hound"));
+}
+
+#[test]
+fn lenient_names_agree_with_from_label_str() {
+  for input in ["cat", " CAT ", "Big Bird\n", "bigbird", ""] {
+    assert_eq!(
+      Lenient::is_known_name(input),
+      Lenient::from_label_str(input).is_some(),
+      "{:?}",
+      input
+    );
+  }
+  assert!(Lenient::is_known_name(" Dog "));
+}
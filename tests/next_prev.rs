@@ -0,0 +1,38 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/next_prev.rs - `next` / `prev` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/sparse.toml", trait = "none")]
+enum Label {}
+
+#[test]
+fn step_forward_to_the_end() {
+  let mut steps = vec![Label::Cat];
+  while let Some(next) = steps.last().unwrap().next() {
+    steps.push(next);
+  }
+  assert_eq!(steps, [Label::Cat, Label::Dog, Label::Bird]);
+}
+
+#[test]
+fn step_back_to_the_start() {
+  let mut steps = vec![Label::Bird];
+  while let Some(prev) = steps.last().unwrap().prev() {
+    steps.push(prev);
+  }
+  assert_eq!(steps, [Label::Bird, Label::Dog, Label::Cat]);
+}
+
+#[test]
+fn unknown_has_no_neighbours() {
+  assert_eq!(Label::Unknown(4).next(), None);
+  assert_eq!(Label::Unknown(4).prev(), None);
+}
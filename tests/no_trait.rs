@@ -0,0 +1,25 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/no_trait.rs - `trait = "none"` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+// 本文件不定义也不引入任何 trait，生成的方法须为固有方法
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none")]
+enum Label {}
+
+#[test]
+fn inherent_methods() {
+  assert_eq!(Label::LABEL_NUM, 3);
+  assert_eq!(Label::from_label_id(5), Label::BigBird);
+  assert_eq!(Label::Dog.to_label_id(), 1);
+  assert_eq!(Label::Dog.to_label_str(), "dog");
+  assert_eq!(Label::from_label_id(9), Label::Unknown(9));
+}
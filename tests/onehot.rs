@@ -0,0 +1,35 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/onehot.rs - `to_onehot` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none")]
+enum Label {}
+
+#[toml_label(
+  file = "tests/labels/labels.toml",
+  trait = "none",
+  output_dim = "max_id",
+  closed = true
+)]
+enum ById {}
+
+#[test]
+fn onehot_by_dense_index() {
+  assert_eq!(Label::Cat.to_onehot(), [1.0, 0.0, 0.0]);
+  assert_eq!(Label::BigBird.to_onehot(), [0.0, 0.0, 1.0]);
+  assert_eq!(Label::Unknown(7).to_onehot(), [0.0; 3]);
+}
+
+#[test]
+fn onehot_by_id_with_max_id_output() {
+  assert_eq!(ById::Dog.to_onehot(), [0.0, 1.0, 0.0, 0.0, 0.0, 0.0]);
+  assert_eq!(ById::BigBird.to_onehot(), [0.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+}
@@ -0,0 +1,51 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/optimize.rs - `optimize` 与普通 `from_label_str` 的一致性测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/aliases.toml", trait = "none")]
+enum Flat {}
+
+#[toml_label(file = "tests/labels/aliases.toml", trait = "none", optimize = true)]
+enum Optimized {}
+
+#[toml_label(
+  file = "tests/labels/aliases.toml",
+  trait = "none",
+  optimize = true,
+  lenient_parse = true
+)]
+enum Lenient {}
+
+/// 命中、别名、未命中（含前缀、同长度与空串）
+const INPUTS: &[&str] = &[
+  "cat", "dog", "bird", "kitty", "puss", "hound", "cow", "ca", "cats", "bard", "", "Cat",
+];
+
+#[test]
+fn optimized_agrees_with_flat() {
+  for &input in INPUTS {
+    assert_eq!(
+      Optimized::from_label_str(input).map(|label| label.to_label_id()),
+      Flat::from_label_str(input).map(|label| label.to_label_id()),
+      "{:?}",
+      input
+    );
+  }
+  assert_eq!(Optimized::from_label_str("kitty"), Some(Optimized::Cat));
+  assert_eq!(Optimized::from_label_str(""), None);
+}
+
+#[test]
+fn optimized_lenient_normalizes_input() {
+  assert_eq!(Lenient::from_label_str(" DOG"), Some(Lenient::Dog));
+  assert_eq!(Lenient::from_label_str("Hound\n"), Some(Lenient::Dog));
+  assert_eq!(Lenient::from_label_str(" "), None);
+}
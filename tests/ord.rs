@@ -0,0 +1,45 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/ord.rs - 封闭模式下 `Ord` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(
+  file = "tests/labels/sparse.toml",
+  trait = "none",
+  closed = true,
+  repr = "u8"
+)]
+enum Label {}
+
+#[test]
+fn ord_agrees_with_id_order() {
+  let mut labels = vec![Label::Bird, Label::Cat, Label::Dog, Label::Cat];
+  labels.sort();
+  assert_eq!(labels, [Label::Cat, Label::Cat, Label::Dog, Label::Bird]);
+  assert!(Label::Dog < Label::Bird);
+  assert_eq!(Label::Cat.max(Label::Dog), Label::Dog);
+}
+
+#[test]
+fn sorting_all_labels_yields_ascending_ids() {
+  let mut all: Vec<Label> = (0..Label::LABEL_NUM as usize)
+    .rev()
+    .filter_map(Label::from_index)
+    .collect();
+  all.sort();
+  for pair in all.windows(2) {
+    assert_eq!(
+      pair[0].cmp(&pair[1]),
+      pair[0].to_label_id().cmp(&pair[1].to_label_id())
+    );
+  }
+  let ids: Vec<u8> = all.iter().map(|label| label.to_label_id()).collect();
+  assert_eq!(ids, [0, 3, 7]);
+}
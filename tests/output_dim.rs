@@ -0,0 +1,37 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/output_dim.rs - `OUTPUT_DIM` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/sparse.toml", trait = "none")]
+enum Dense {}
+
+#[toml_label(
+  file = "tests/labels/sparse.toml",
+  trait = "none",
+  output_dim = "max_id"
+)]
+enum MaxId {}
+
+#[test]
+fn dense_equals_label_num() {
+  assert_eq!(Dense::OUTPUT_DIM, Dense::LABEL_NUM as usize);
+  assert_eq!(Dense::OUTPUT_DIM, 3);
+  assert_eq!(Dense::Bird.to_onehot(), [0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn max_id_covers_every_id() {
+  assert_eq!(MaxId::OUTPUT_DIM, MaxId::MAX_LABEL_ID as usize + 1);
+  assert_eq!(MaxId::OUTPUT_DIM, 8);
+  let buffer = [0.0f32; MaxId::OUTPUT_DIM];
+  assert_eq!(buffer.len(), 8);
+  assert_eq!(MaxId::Dog.to_onehot()[3], 1.0);
+}
@@ -0,0 +1,40 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/parse.rs - `parse` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none")]
+enum Label {}
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none", closed = true)]
+enum ClosedLabel {}
+
+#[test]
+fn names_are_tried_first() {
+  assert_eq!(Label::parse("cat"), Some(Label::Cat));
+  assert_eq!(Label::parse("big bird"), Some(Label::BigBird));
+}
+
+#[test]
+fn numeric_strings_are_ids() {
+  assert_eq!(Label::parse("1"), Some(Label::Dog));
+  assert_eq!(Label::parse("9"), Some(Label::Unknown(9)));
+  assert_eq!(ClosedLabel::parse("5"), Some(ClosedLabel::BigBird));
+  assert_eq!(ClosedLabel::parse("9"), None);
+}
+
+#[test]
+fn garbage_is_rejected() {
+  assert_eq!(Label::parse("horse"), None);
+  assert_eq!(Label::parse(""), None);
+  assert_eq!(Label::parse("-1"), None);
+  assert_eq!(Label::parse("1.0"), None);
+  assert_eq!(ClosedLabel::parse("horse"), None);
+}
@@ -0,0 +1,22 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/quoted_ids.rs - 数字字符串 id 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/quoted.toml", trait = "none")]
+enum Label {}
+
+#[test]
+fn quoted_ids_are_integers() {
+  assert_eq!(
+    [Label::Cat, Label::Dog, Label::BigBird].map(|label| label.to_label_id()),
+    [0, 1, 5]
+  );
+}
@@ -0,0 +1,44 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/repr_bytes.rs - `REPR_BYTES` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use std::mem::size_of;
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none")]
+enum Plain {}
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none", repr = "u8")]
+enum Narrow {}
+
+#[toml_label(
+  file = "tests/labels/labels.toml",
+  trait = "none",
+  repr = "u16",
+  closed = true
+)]
+enum Closed {}
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none", id_type = "i64")]
+enum Wide {}
+
+#[test]
+fn repr_bytes_is_the_id_width() {
+  assert_eq!(Plain::REPR_BYTES, size_of::<u32>());
+  assert_eq!(Narrow::REPR_BYTES, 1);
+  assert_eq!(Wide::REPR_BYTES, 8);
+  let _ = (Plain::Cat, Narrow::Cat, Wide::Cat);
+}
+
+#[test]
+fn closed_repr_bytes_is_the_enum_size() {
+  assert_eq!(Closed::REPR_BYTES, 2);
+  assert_eq!(Closed::REPR_BYTES, size_of::<Closed>());
+}
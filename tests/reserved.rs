@@ -0,0 +1,51 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/reserved.rs - `reserved` 保留区间测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::{TomlLabel, toml_label};
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none", reserved = [[100, 199], [7, 7]])]
+enum Label {}
+
+#[derive(Debug, PartialEq, TomlLabel)]
+#[toml_label(file = "tests/labels/labels.toml", trait = "none", reserved = [[10, 20]])]
+enum Derived {
+  Cat,
+  Dog,
+  BigBird,
+  Unknown(u32),
+  Reserved(u32),
+}
+
+#[test]
+fn ids_inside_reserved_ranges() {
+  assert_eq!(Label::from_label_id(100), Label::Reserved(100));
+  assert_eq!(Label::from_label_id(150), Label::Reserved(150));
+  assert_eq!(Label::from_label_id(199), Label::Reserved(199));
+  assert_eq!(Label::from_label_id(7), Label::Reserved(7));
+  assert_eq!(Derived::from_label_id(15), Derived::Reserved(15));
+}
+
+#[test]
+fn ids_outside_reserved_ranges() {
+  assert_eq!(Label::from_label_id(99), Label::Unknown(99));
+  assert_eq!(Label::from_label_id(200), Label::Unknown(200));
+  assert_eq!(Label::from_label_id(6), Label::Unknown(6));
+  assert_eq!(Label::from_label_id(1), Label::Dog);
+  assert_eq!(Derived::from_label_id(21), Derived::Unknown(21));
+}
+
+#[test]
+fn reserved_labels_are_not_valid_labels() {
+  assert!(Label::try_from_label_id(150).is_err());
+  assert_eq!(Label::Reserved(150).to_label_id(), 150);
+  assert_eq!(Label::Reserved(150).to_label_str(), "reserved150");
+  assert_eq!(Label::Reserved(150).index(), None);
+  assert_eq!(Label::RESERVED_IDS, &[(100, 199), (7, 7)]);
+}
@@ -0,0 +1,26 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/sanitize.rs - 变体名清洗测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/exotic.toml", trait = "none")]
+enum Label {}
+
+#[test]
+fn sanitized_variants_keep_original_names() {
+  assert_eq!(Label::A_b.to_label_str(), "a+b");
+  assert_eq!(Label::Coco__person.to_label_str(), "coco::person");
+  assert_eq!(Label::V2_car.to_label_str(), "v2/car");
+  assert_eq!(Label::_3dBox.to_label_str(), "3d box");
+  assert_eq!(
+    Label::from_label_str("coco::person"),
+    Some(Label::Coco__person)
+  );
+}
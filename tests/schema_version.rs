@@ -0,0 +1,26 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/schema_version.rs - `SCHEMA_VERSION` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/versioned.toml", trait = "none")]
+enum Label {}
+
+#[test]
+fn version_is_a_const() {
+  assert_eq!(Label::SCHEMA_VERSION, 3);
+}
+
+#[test]
+fn version_is_not_a_label() {
+  assert_eq!(Label::LABEL_NUM, 2);
+  assert_eq!(Label::from_label_str("version"), None);
+  assert_eq!(Label::DICTIONARY, &["cat", "dog"]);
+}
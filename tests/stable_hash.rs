@@ -0,0 +1,37 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/stable_hash.rs - `stable_hash` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none")]
+enum Label {}
+
+/// 独立实现的 FNV-1a 64 位哈希，用于对照
+fn fnv1a(bytes: &[u8]) -> u64 {
+  bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+    (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+  })
+}
+
+#[test]
+fn stable_hash_is_fnv1a_of_the_name() {
+  assert_eq!(Label::Cat.stable_hash(), fnv1a(b"cat"));
+  assert_eq!(Label::BigBird.stable_hash(), fnv1a(b"big bird"));
+  assert_eq!(Label::Cat.stable_hash(), 17718013163177550631);
+}
+
+#[test]
+fn stable_hash_of_unknown_depends_on_the_id() {
+  assert_eq!(Label::Unknown(7).stable_hash(), 1296579735476671978);
+  assert_ne!(
+    Label::Unknown(7).stable_hash(),
+    Label::Unknown(8).stable_hash()
+  );
+}
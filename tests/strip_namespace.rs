@@ -0,0 +1,51 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/strip_namespace.rs - 去除命名空间测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(
+  file = "tests/labels/namespaced.toml",
+  trait = "none",
+  strip_delimiter = "/"
+)]
+enum ByDelimiter {}
+
+#[toml_label(
+  file = "tests/labels/namespaced.toml",
+  trait = "none",
+  strip_prefix = "coco/"
+)]
+enum ByPrefix {}
+
+#[test]
+fn delimiter_strips_up_to_the_last_occurrence() {
+  assert_eq!(ByDelimiter::Person.to_label_id(), 0);
+  assert_eq!(ByDelimiter::Car.to_label_id(), 1);
+  assert_eq!(ByDelimiter::V2_bus.to_label_id(), 2);
+  assert_eq!(ByDelimiter::Plain.to_label_id(), 3);
+}
+
+#[test]
+fn prefix_strips_only_matching_names() {
+  assert_eq!(ByPrefix::Person.to_label_id(), 0);
+  assert_eq!(ByPrefix::V2_bus.to_label_id(), 2);
+  assert_eq!(ByPrefix::Plain.to_label_id(), 3);
+}
+
+#[test]
+fn original_names_are_kept() {
+  assert_eq!(ByDelimiter::Person.to_label_str(), "coco/person");
+  assert_eq!(
+    ByDelimiter::from_label_str("coco/car"),
+    Some(ByDelimiter::Car)
+  );
+  assert_eq!(ByDelimiter::from_label_str("car"), None);
+  assert_eq!(ByPrefix::Car.to_label_str(), "coco/car");
+}
@@ -0,0 +1,45 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/trait_path.rs - `trait_path` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+/// 模拟共享 crate 在较深路径下再导出的 trait
+mod shared {
+  pub mod reexports {
+    pub mod labels {
+      pub trait WithLabel {
+        const LABEL_NUM: u32;
+        fn from_label_id(label_id: u32) -> Self;
+        fn to_label_str(&self) -> String;
+        fn to_label_id(&self) -> u32;
+      }
+    }
+  }
+}
+
+/// 宏调用处没有 `use` 该 trait
+mod user {
+  #[shanan_macro::toml_label(
+    file = "tests/labels/labels.toml",
+    trait_path = "crate::shared::reexports::labels::WithLabel"
+  )]
+  pub enum Label {}
+}
+
+use shared::reexports::labels::WithLabel;
+
+fn label_name<T: WithLabel>(label_id: u32) -> String {
+  T::from_label_id(label_id).to_label_str()
+}
+
+#[test]
+fn trait_is_implemented_through_the_full_path() {
+  assert_eq!(<user::Label as WithLabel>::LABEL_NUM, 3);
+  assert_eq!(label_name::<user::Label>(5), "big bird");
+  assert_eq!(user::Label::Dog.to_label_id(), 1);
+}
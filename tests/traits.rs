@@ -0,0 +1,45 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/traits.rs - 多个 trait 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+mod common;
+
+use shanan_macro::toml_label;
+
+/// 第三方 crate 中与 `WithLabel` 方法集相同的 trait
+mod third_party {
+  pub trait ClassId {
+    const LABEL_NUM: u32;
+    fn from_label_id(label_id: u32) -> Self;
+    fn to_label_str(&self) -> String;
+    fn to_label_id(&self) -> u32;
+  }
+}
+
+#[toml_label(
+  file = "tests/labels/labels.toml",
+  traits = ["common::WithLabel", "crate::third_party::ClassId"]
+)]
+enum Label {}
+
+fn with_label<T: common::WithLabel>(id: u32) -> String {
+  T::from_label_id(id).to_label_str()
+}
+
+fn class_id<T: third_party::ClassId>(id: u32) -> u32 {
+  let label = T::from_label_id(id);
+  assert!(!label.to_label_str().is_empty());
+  label.to_label_id() + T::LABEL_NUM
+}
+
+#[test]
+fn both_traits_are_implemented() {
+  assert_eq!(with_label::<Label>(1), "dog");
+  assert_eq!(class_id::<Label>(5), 8);
+}
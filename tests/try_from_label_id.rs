@@ -0,0 +1,54 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/try_from_label_id.rs - `try_from_label_id` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none")]
+enum Label {}
+
+#[test]
+fn known_ids_are_ok() {
+  assert_eq!(Label::try_from_label_id(0), Ok(Label::Cat));
+  assert_eq!(Label::try_from_label_id(5), Ok(Label::BigBird));
+}
+
+#[test]
+fn unknown_ids_are_errors() {
+  let error = Label::try_from_label_id(3).unwrap_err();
+  assert_eq!(error, InvalidLabel(3));
+  assert_eq!(error.to_string(), "invalid label id 3 for Label");
+  let error: Box<dyn std::error::Error> = Box::new(error);
+  assert_eq!(error.to_string(), "invalid label id 3 for Label");
+}
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none", try_from = true)]
+enum Converted {}
+
+// 未开启 `try_from` 时使用方可以自行实现 `From<u32>`
+#[toml_label(file = "tests/labels/labels.toml", trait = "none")]
+enum Lossy {}
+
+impl From<u32> for Lossy {
+  fn from(id: u32) -> Self {
+    Lossy::from_label_id(id)
+  }
+}
+
+#[test]
+fn try_from_is_opt_in() {
+  assert_eq!(Converted::try_from(5), Ok(Converted::BigBird));
+  assert_eq!(Converted::try_from(3), Err(InvalidConverted(3)));
+  let label: Result<Converted, _> = 1u32.try_into();
+  assert_eq!(label, Ok(Converted::Dog));
+  assert_eq!(Lossy::from(0), Lossy::Cat);
+  assert_eq!(Lossy::from(1), Lossy::Dog);
+  assert_eq!(Lossy::from(3), Lossy::Unknown(3));
+  assert_eq!(Lossy::try_from_label_id(3), Err(InvalidLossy(3)));
+}
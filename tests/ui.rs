@@ -0,0 +1,19 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/ui.rs - 编译失败用例
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+// trybuild 在 `target/tests/trybuild/shanan-macro` 下编译每个用例，`CARGO_MANIFEST_DIR`
+// 指向该目录，因此用例中的标签文件写作 `../../../../tests/ui/labels/...`。
+// 修改错误信息后以 `TRYBUILD=overwrite cargo test --test ui` 更新 `.stderr` 文件。
+
+#[test]
+fn ui() {
+  let cases = trybuild::TestCases::new();
+  cases.compile_fail("tests/ui/*.rs");
+}
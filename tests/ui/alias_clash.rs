@@ -0,0 +1,28 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/ui/alias_clash.rs - 别名与其他名称冲突时报错
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+#[shanan_macro::toml_label(file = "../../../../tests/ui/labels/alias_clash.toml", trait = "none")]
+enum Label {}
+
+#[shanan_macro::toml_label(
+  file = "../../../../tests/ui/labels/alias_lenient_clash.toml",
+  trait = "none",
+  lenient_parse = true
+)]
+enum Lenient {}
+
+#[shanan_macro::toml_label(
+  file = "../../../../tests/ui/labels/alias_include.toml",
+  include = "../../../../tests/ui/labels/alias_include_base.toml",
+  trait = "none"
+)]
+enum Included {}
+
+fn main() {}
@@ -0,0 +1,23 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/ui/copy_false_move.rs - `copy = false` 的枚举移动后不能再使用
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+#[shanan_macro::toml_label(
+  file = "../../../../tests/ui/labels/labels.toml",
+  trait = "none",
+  copy = false
+)]
+pub enum Label {}
+
+fn main() {
+  let label = Label::Cat;
+  let moved = label;
+  let _ = label.to_label_id();
+  let _ = moved;
+}
@@ -0,0 +1,46 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/ui/derive_variants.rs - 派生宏形式下枚举变体与标签文件不一致
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::TomlLabel;
+
+// 缺少 `BigBird` 与 `Unknown`，多出 `Fish`，`Dog` 的判别值与 id 不符
+#[derive(TomlLabel)]
+#[toml_label(file = "../../../../tests/ui/labels/labels.toml", trait = "none")]
+pub enum Mismatched {
+  Cat,
+  Dog = 3,
+  Fish,
+}
+
+// `Unknown` 不带 id，标签变体带有字段
+#[derive(TomlLabel)]
+#[toml_label(file = "../../../../tests/ui/labels/labels.toml", trait = "none")]
+pub enum Fields {
+  Cat(u8),
+  Dog,
+  BigBird,
+  Unknown,
+}
+
+// 派生宏不能改写枚举定义本身
+#[derive(TomlLabel)]
+#[toml_label(
+  file = "../../../../tests/ui/labels/labels.toml",
+  trait = "none",
+  non_exhaustive = true
+)]
+pub enum Unsupported {
+  Cat,
+  Dog,
+  BigBird,
+  Unknown(u32),
+}
+
+fn main() {}
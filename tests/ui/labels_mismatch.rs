@@ -0,0 +1,18 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/ui/labels_mismatch.rs - 标签名称不一致时 `assert_labels_match!` 报错
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+shanan_macro::assert_labels_match!(
+  "../../../../tests/ui/labels/labels.toml",
+  "../../../../tests/ui/labels/mismatch.toml"
+);
+
+shanan_macro::assert_labels_match!("../../../../tests/ui/labels/labels.toml");
+
+fn main() {}
@@ -0,0 +1,32 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/ui/trait_keys.rs - `trait`、`trait_path` 与 `traits` 同时给出
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+
+#[shanan_macro::toml_label(
+  file = "../../../../tests/ui/labels/labels.toml",
+  trait = "none",
+  traits = ["WithLabel"]
+)]
+enum Label {}
+
+#[derive(shanan_macro::TomlLabel)]
+#[toml_label(
+  file = "../../../../tests/ui/labels/labels.toml",
+  trait_path = "crate::WithLabel",
+  trait = "none"
+)]
+enum Derived {
+  Cat,
+  Dog,
+  BigBird,
+  Unknown(u32),
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/unknown_above.rs - `unknown_above` 测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(file = "tests/labels/labels.toml", trait = "none", unknown_above = 10)]
+enum Label {}
+
+#[test]
+fn ids_below_the_bound_are_kept() {
+  assert_eq!(Label::from_label_id(5), Label::BigBird);
+  assert_eq!(Label::from_label_id(7), Label::Unknown(7));
+  assert_eq!(Label::from_label_id(9), Label::Unknown(9));
+}
+
+#[test]
+fn ids_at_or_above_the_bound_collapse() {
+  assert_eq!(Label::from_label_id(10), Label::Unknown(10));
+  assert_eq!(Label::from_label_id(12345), Label::Unknown(10));
+  assert_eq!(Label::from_label_id(u32::MAX), Label::Unknown(10));
+}
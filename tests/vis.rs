@@ -0,0 +1,24 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/vis.rs - `vis` 参数测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+mod labels {
+  shanan_macro::toml_label_enum!(
+    name = "Label",
+    file = "tests/labels/labels.toml",
+    trait = "none",
+    vis = "pub(crate)"
+  );
+}
+
+#[test]
+fn restricted_enum_is_usable_within_the_crate() {
+  assert_eq!(labels::Label::from_label_id(1), labels::Label::Dog);
+  assert_eq!(labels::Label::Dog.to_label_str(), "dog");
+}
@@ -0,0 +1,50 @@
+// 该文件是 Shanan （山南西风） 项目的一部分。
+// tests/wide_ids.rs - 宽 id 类型测试
+//
+// 本程序遵循 GNU Affero 通用公共许可证（AGPL）许可协议。
+// 本程序的发布旨在提供实用价值，但不作任何形式的担保，
+// 包括但不限于对适销性或特定用途适用性的默示担保。
+// 更多详情请参阅 GNU 通用公共许可证。
+//
+// Copyright (C) 2026 Johann Li <me@qinka.pro>, ETVP
+
+use shanan_macro::toml_label;
+
+#[toml_label(
+  file = "tests/labels/wide.toml",
+  trait = "none",
+  id_type = "u64",
+  try_from = true
+)]
+enum Label {}
+
+#[toml_label(
+  file = "tests/labels/wide.toml",
+  trait = "none",
+  repr = "i64",
+  closed = true,
+  try_from = true
+)]
+enum ClosedLabel {}
+
+#[test]
+fn ids_above_u32_max() {
+  assert_eq!(Label::Big.to_label_id(), 5_000_000_000u64);
+  assert_eq!(Label::from_label_id(5_000_000_000), Label::Big);
+  assert_eq!(Label::from_label_id(i64::MAX as u64), Label::Huge);
+  assert_eq!(Label::from_label_id(u64::MAX), Label::Unknown(u64::MAX));
+  assert_eq!(Label::LABEL_NUM, 3u32);
+  assert_eq!(ClosedLabel::Big as i64, 5_000_000_000);
+}
+
+#[test]
+fn try_from_wide_ids() {
+  assert_eq!(Label::try_from_label_id(5_000_000_000), Ok(Label::Big));
+  assert_eq!(Label::try_from(5_000_000_000u64), Ok(Label::Big));
+  assert_eq!(
+    Label::try_from(u32::MAX as u64 + 1),
+    Err(InvalidLabel(u32::MAX as u64 + 1))
+  );
+  let label: Result<ClosedLabel, _> = 5_000_000_000i64.try_into();
+  assert_eq!(label, Ok(ClosedLabel::Big));
+}